@@ -0,0 +1,139 @@
+use rayon::prelude::*;
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use super::super::{RadixKey, Radixable};
+
+const SAMPLE_SIZE: usize = 256;
+const PARALLEL_THRESHOLD: usize = 100_000;
+
+/// # Check error
+///
+/// Error reported by the checked sorts.
+///
+/// - `NotSorted`: the item at `index` is greater than the next one, according
+///   to `partial_cmp`.
+/// - `InconsistentOrder`: `partial_cmp` and the `Radixable` key disagree on
+///   the order of two items. Since the radix sorts use the key and the
+///   fallbacks use `partial_cmp`, the output order depends on the size of the
+///   array. `partial_cmp` is the order given by `PartialOrd`, `key_order` is
+///   the order of the keys.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CheckError<T> {
+    NotSorted { index: usize, left: T, right: T },
+    InconsistentOrder {
+        left: T,
+        right: T,
+        partial_cmp: Ordering,
+        key_order: Ordering,
+    },
+}
+
+impl<T> CheckError<T> {
+    // The message without the items, for the items which are not `Debug`.
+    pub(crate) fn reason(&self) -> String {
+        match self {
+            CheckError::NotSorted { index, .. } => format!(
+                "[voracious_sort] Output not sorted: the item at index {} is \
+                 greater than the next item.",
+                index
+            ),
+            CheckError::InconsistentOrder { partial_cmp, key_order, .. } => {
+                format!(
+                    "[voracious_sort] Radixable key and PartialOrd disagree: \
+                     partial_cmp gives {:?} but the keys give {:?}.",
+                    partial_cmp, key_order
+                )
+            },
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for CheckError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (left, right) = match self {
+            CheckError::NotSorted { left, right, .. } => (left, right),
+            CheckError::InconsistentOrder { left, right, .. } => (left, right),
+        };
+        write!(f, "{} Left: {:?}, right: {:?}.", self.reason(), left, right)
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for CheckError<T> {}
+
+pub(crate) fn check_pair<T, K>(left: &T, right: &T) -> Result<(), CheckError<T>>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let key_order = left.into_key_type().cmp(&right.into_key_type());
+    match left.partial_cmp(right) {
+        // Equal items can be in any order, and items which can not be
+        // compared (NaN) are not checked.
+        None | Some(Ordering::Equal) => Ok(()),
+        Some(partial_cmp) if partial_cmp == key_order => Ok(()),
+        Some(partial_cmp) => Err(CheckError::InconsistentOrder {
+            left: *left,
+            right: *right,
+            partial_cmp,
+            key_order,
+        }),
+    }
+}
+
+/// Check, on a sample of pairs, that `partial_cmp` agrees with the key order.
+///
+/// The sample is made of neighbours and of items far from each others.
+pub fn check_key_order<T, K>(arr: &[T]) -> Result<(), CheckError<T>>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() < 2 {
+        return Ok(());
+    }
+
+    let step = (arr.len() / SAMPLE_SIZE).max(1);
+    for i in (0..arr.len() - 1).step_by(step) {
+        check_pair(&arr[i], &arr[i + 1])?;
+        check_pair(&arr[i], &arr[arr.len() - 1 - i])?;
+    }
+
+    Ok(())
+}
+
+/// Check that the array is sorted according to `partial_cmp`, in parallel for
+/// large arrays.
+pub fn check_sorted<T: Copy + PartialOrd + Send + Sync>(
+    arr: &[T],
+) -> Result<(), CheckError<T>> {
+    let is_greater =
+        |w: &[T]| w[0].partial_cmp(&w[1]) == Some(Ordering::Greater);
+
+    let index = if arr.len() >= PARALLEL_THRESHOLD {
+        arr.par_windows(2).position_first(is_greater)
+    } else {
+        arr.windows(2).position(is_greater)
+    };
+
+    match index {
+        None => Ok(()),
+        Some(index) => Err(CheckError::NotSorted {
+            index,
+            left: arr[index],
+            right: arr[index + 1],
+        }),
+    }
+}
+
+/// Check a sorted array: the key order is checked first, since an
+/// inconsistent key is the most likely cause of an unsorted output.
+pub fn check<T, K>(arr: &[T]) -> Result<(), CheckError<T>>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    check_key_order(arr)?;
+    check_sorted(arr)
+}
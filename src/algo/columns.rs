@@ -0,0 +1,173 @@
+use std::mem::size_of;
+
+use super::super::sorts::cached_key_sort::{
+    run_cached_key_task, CachedKey, CachedKeyTask,
+};
+use super::super::sorts::lsd_stable_sort::lsd_stable_radixsort;
+use super::super::traits::radix_key::key_to_u128;
+use super::super::{RadixKey, Radixable};
+use super::permutation::{apply_permutation, PermIndex};
+
+/// # Column
+///
+/// A column of a table stored as separate vectors. Every column can be
+/// permuted; only the key columns give a radix key for their rows.
+///
+/// `Column` is implemented for `Vec<T>` when `T` is a `RadixKey` (integers,
+/// floats, `bool`, `char`, `Option`, ...). Columns of other types are
+/// wrapped into a `Payload`.
+pub trait Column {
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool { self.len() == 0 }
+    /// Move the rows so that the row `i` is the row which was at `perm[i]`.
+    /// `perm` is the identity when the method returns.
+    fn apply_permutation(&mut self, perm: &mut [usize]);
+    /// Number of bits of the radix key, zero if the column has no key.
+    fn key_bits(&self) -> usize { 0 }
+    /// Radix key of a row, on `key_bits` bits.
+    fn sort_key(&self, _row: usize) -> u128 { 0 }
+}
+
+impl<T: RadixKey> Column for Vec<T> {
+    fn len(&self) -> usize { self.as_slice().len() }
+    fn apply_permutation(&mut self, perm: &mut [usize]) {
+        apply_permutation(self, perm);
+    }
+    fn key_bits(&self) -> usize { size_of::<T::Key>() * 8 }
+    fn sort_key(&self, row: usize) -> u128 { key_to_u128(&self[row]) }
+}
+
+/// # Payload
+///
+/// A column without a key, of any type: it is only permuted with the key
+/// columns.
+pub struct Payload<'a, T>(pub &'a mut [T]);
+
+impl<'a, T> Column for Payload<'a, T> {
+    fn len(&self) -> usize { self.0.len() }
+    fn apply_permutation(&mut self, perm: &mut [usize]) {
+        apply_permutation(self.0, perm);
+    }
+}
+
+/// # Sort key spec
+///
+/// A key column, given by its index in the columns, and its direction.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SortKeySpec {
+    pub column: usize,
+    pub descending: bool,
+}
+
+impl SortKeySpec {
+    pub fn asc(column: usize) -> SortKeySpec {
+        SortKeySpec { column, descending: false }
+    }
+    pub fn desc(column: usize) -> SortKeySpec {
+        SortKeySpec { column, descending: true }
+    }
+}
+
+// One stable LSD sort of the rows, in the order of `perm`, by the key of
+// one column. The rows are cached on u32 when the table is short enough.
+struct SortByColumn<'a> {
+    perm: &'a mut [usize],
+    column: &'a dyn Column,
+    descending: bool,
+}
+
+impl<'a> CachedKeyTask for SortByColumn<'a> {
+    type Output = ();
+
+    fn run<U, I>(self, narrow: fn(u128) -> U)
+    where
+        CachedKey<U, I>: Radixable<U>,
+        U: RadixKey,
+        I: PermIndex,
+    {
+        let bits = self.column.key_bits();
+        let flip = if self.descending { u128::MAX >> (128 - bits) } else { 0 };
+
+        let mut keys: Vec<CachedKey<U, I>> = self
+            .perm
+            .iter()
+            .map(|&row| CachedKey {
+                key: narrow(self.column.sort_key(row) ^ flip),
+                index: I::from_usize(row),
+            })
+            .collect();
+
+        lsd_stable_radixsort(&mut keys, 8);
+
+        for (row, cached) in self.perm.iter_mut().zip(keys.iter()) {
+            *row = cached.index.to_usize();
+        }
+    }
+}
+
+/// # Sort columns
+///
+/// Sort the rows of a table stored as separate columns, like the SQL
+/// `ORDER BY a, b DESC, c`: the rows are sorted by the first key column, the
+/// ties by the second key column, and so on.
+///
+/// The permutation of the rows is computed by stable LSD sorts, one per key
+/// column, the least significant column first. It is then applied to every
+/// column, key columns and payloads. The sort is stable.
+///
+/// Panics if the columns have different lengths, or if a spec does not give
+/// a key column.
+///
+/// ```
+/// use voracious_radix_sort::{sort_columns, Column, Payload, SortKeySpec};
+///
+/// let mut region: Vec<u8> = vec![2, 1, 2, 1];
+/// let mut score: Vec<f32> = vec![0.5, 0.1, 0.9, 0.7];
+/// let mut mut_names = vec!["a", "b", "c", "d"];
+/// let mut names = Payload(&mut mut_names);
+///
+/// let specs = [SortKeySpec::asc(0), SortKeySpec::desc(1)];
+/// sort_columns(&mut [&mut region, &mut score, &mut names], &specs);
+///
+/// assert_eq!(region, vec![1, 1, 2, 2]);
+/// assert_eq!(score, vec![0.7, 0.1, 0.9, 0.5]);
+/// assert_eq!(mut_names, vec!["d", "b", "c", "a"]);
+/// ```
+pub fn sort_columns(columns: &mut [&mut dyn Column], specs: &[SortKeySpec]) {
+    if columns.is_empty() {
+        return;
+    }
+
+    let size = columns[0].len();
+    assert!(
+        columns.iter().all(|column| column.len() == size),
+        "[sort_columns] The columns must have the same length."
+    );
+    for spec in specs.iter() {
+        assert!(
+            spec.column < columns.len() && columns[spec.column].key_bits() > 0,
+            "[sort_columns] The column {} is not a key column.",
+            spec.column
+        );
+    }
+
+    if size < 2 || specs.is_empty() {
+        return;
+    }
+
+    let mut perm: Vec<usize> = (0..size).collect();
+    for spec in specs.iter().rev() {
+        let column = &*columns[spec.column];
+        let task = SortByColumn {
+            perm: &mut perm,
+            column,
+            descending: spec.descending,
+        };
+        run_cached_key_task(column.key_bits(), size, task);
+    }
+
+    for column in columns.iter_mut() {
+        let mut column_perm = perm.to_vec();
+        column.apply_permutation(&mut column_perm);
+    }
+}
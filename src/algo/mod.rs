@@ -1,4 +1,11 @@
+pub mod checked;
+pub mod columns;
 pub mod k_way_merge;
 // pub mod k_way_merge_mt;
+pub mod permutation;
+pub mod radix_join;
+pub mod radix_sorted_vec;
+pub mod registry;
 pub mod regions_graph;
+pub mod streaming_sorter;
 pub mod verge_sort_heuristic;
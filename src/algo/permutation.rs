@@ -0,0 +1,75 @@
+/// # Apply permutation in place
+///
+/// Move the items of `arr` so that the item at index `i` is the item which
+/// was at index `perm[i]`.
+///
+/// The permutation is applied cycle by cycle (cycle leader algorithm): each
+/// item is moved once, with swaps, and no buffer is allocated. `perm` is used
+/// to mark the visited indices, it is the identity when the function returns.
+///
+/// Panics if `arr` and `perm` have different lengths, or if `perm` is not a
+/// permutation of `0..arr.len()`.
+///
+/// ```
+/// use voracious_radix_sort::apply_permutation_in_place;
+///
+/// let mut arr = vec!['a', 'b', 'c', 'd'];
+/// let mut perm = vec![2, 0, 3, 1];
+/// apply_permutation_in_place(&mut arr, &mut perm);
+/// assert_eq!(arr, vec!['c', 'a', 'd', 'b']);
+/// ```
+pub fn apply_permutation_in_place<T>(arr: &mut [T], perm: &mut [u32]) {
+    assert!(arr.len() <= u32::MAX as usize);
+    apply_permutation(arr, perm);
+}
+
+// Index of a permutation: u32 when the array is short enough, to halve the
+// memory of the indices, usize otherwise.
+pub(crate) trait PermIndex: Copy + Send + Sync {
+    fn from_usize(index: usize) -> Self;
+    fn to_usize(self) -> usize;
+}
+
+impl PermIndex for u32 {
+    #[inline]
+    fn from_usize(index: usize) -> Self { index as u32 }
+    #[inline]
+    fn to_usize(self) -> usize { self as usize }
+}
+
+impl PermIndex for usize {
+    #[inline]
+    fn from_usize(index: usize) -> Self { index }
+    #[inline]
+    fn to_usize(self) -> usize { self }
+}
+
+pub(crate) fn apply_permutation<T, I: PermIndex>(
+    arr: &mut [T],
+    perm: &mut [I],
+) {
+    assert_eq!(
+        arr.len(),
+        perm.len(),
+        "[apply_permutation_in_place] arr and perm have different lengths."
+    );
+
+    for leader in 0..perm.len() {
+        let mut current = leader;
+        loop {
+            let next = perm[current].to_usize();
+            perm[current] = I::from_usize(current);
+            if next == leader {
+                break;
+            }
+            // In a permutation, only the leader of the cycle can be reached
+            // twice.
+            assert!(
+                perm[next].to_usize() != next,
+                "[apply_permutation_in_place] perm is not a permutation."
+            );
+            arr.swap(current, next);
+            current = next;
+        }
+    }
+}
@@ -0,0 +1,467 @@
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+use super::super::algo::permutation::PermIndex;
+use super::super::sorts::cached_key_sort::{
+    run_cached_key_task, CachedKey, CachedKeyTask,
+};
+use super::super::sorts::msd_sort::copy_by_histogram;
+use super::super::sorts::utils::{
+    compute_max_level, compute_offset, get_histogram, prefix_sums, Params,
+};
+use super::super::traits::radix_key::key_to_u128;
+use super::super::{RadixKey, RadixSort, Radixable};
+
+const RADIX: usize = 8;
+// A pair of buckets with at most this many pairs fits in the cache.
+const BUCKET_SIZE: usize = 1 << 14;
+
+#[derive(Copy, Clone, PartialEq)]
+enum Kind {
+    Inner,
+    Left,
+    Semi,
+    Anti,
+}
+
+type Bucket<'a, U, I> = (&'a mut [CachedKey<U, I>], &'a mut [CachedKey<U, I>]);
+
+fn extract_keys<T, K, F, U, I>(
+    arr: &[T],
+    extract: &F,
+    narrow: fn(u128) -> U,
+) -> Vec<CachedKey<U, I>>
+where
+    K: RadixKey,
+    F: Fn(&T) -> K,
+    I: PermIndex,
+{
+    arr.iter()
+        .enumerate()
+        .map(|(index, item)| CachedKey {
+            key: narrow(key_to_u128(&extract(item))),
+            index: I::from_usize(index),
+        })
+        .collect()
+}
+
+// Scatter the pairs on the digit given by `p`, and return the boundaries of
+// the buckets. The scatter is stable, the pairs of a bucket are still sorted
+// by index.
+fn partition<U, I>(keys: &mut [CachedKey<U, I>], p: &Params) -> Vec<usize>
+where
+    CachedKey<U, I>: Radixable<U>,
+    U: RadixKey,
+    I: PermIndex,
+{
+    let dummy = keys[0];
+    let (mask, shift) = dummy.get_mask_and_shift_from_left(p);
+    let histogram = get_histogram(keys, p, mask, shift);
+    let (p_sums, mut heads, _) = prefix_sums(&histogram);
+    let buffer = keys.to_vec();
+    copy_by_histogram(keys.len(), &buffer, keys, &mut heads, mask, shift);
+
+    p_sums
+}
+
+// Both sides are partitioned on the digit of `p`, and the oversized pairs of
+// buckets on the next digits, until they fit in the cache.
+fn co_partition_rec<'a, U, I>(
+    left: &'a mut [CachedKey<U, I>],
+    right: &'a mut [CachedKey<U, I>],
+    p: Params,
+    buckets: &mut Vec<Bucket<'a, U, I>>,
+) where
+    CachedKey<U, I>: Radixable<U>,
+    U: RadixKey,
+    I: PermIndex,
+{
+    let left_sums = partition(left, &p);
+    let right_sums = partition(right, &p);
+
+    let mut left_rest = left;
+    let mut right_rest = right;
+    for i in 0..p.radix_range {
+        let (left_bucket, l) =
+            left_rest.split_at_mut(left_sums[i + 1] - left_sums[i]);
+        let (right_bucket, r) =
+            right_rest.split_at_mut(right_sums[i + 1] - right_sums[i]);
+        left_rest = l;
+        right_rest = r;
+        if left_bucket.is_empty() {
+            continue;
+        }
+
+        let oversized = left_bucket.len() + right_bucket.len() > BUCKET_SIZE;
+        if oversized && !right_bucket.is_empty() && p.level < p.max_level - 1 {
+            let new_params = p.new_level(p.level + 1);
+            co_partition_rec(left_bucket, right_bucket, new_params, buckets);
+        } else {
+            buckets.push((left_bucket, right_bucket));
+        }
+    }
+}
+
+// Both sides are partitioned with the same digits, so that the items with
+// equal keys end up in the same bucket on both sides.
+fn co_partition<'a, U, I>(
+    left: &'a mut [CachedKey<U, I>],
+    right: &'a mut [CachedKey<U, I>],
+) -> Vec<Bucket<'a, U, I>>
+where
+    CachedKey<U, I>: Radixable<U>,
+    U: RadixKey,
+    I: PermIndex,
+{
+    if left.is_empty() || right.is_empty() {
+        return vec![(left, right)];
+    }
+
+    let bits = left[0].type_size();
+    let offset = compute_offset(left, RADIX).0;
+    let offset = offset.min(compute_offset(right, RADIX).0);
+    let max_level = compute_max_level(bits, offset, RADIX);
+    if max_level == 0 {
+        return vec![(left, right)];
+    }
+    let p = Params::new(0, RADIX, offset, max_level);
+
+    let mut buckets = Vec::with_capacity(p.radix_range);
+    co_partition_rec(left, right, p, &mut buckets);
+
+    buckets
+}
+
+fn merge_join<U, I, O, E>(
+    left: &[CachedKey<U, I>],
+    right: &[CachedKey<U, I>],
+    kind: Kind,
+    emit: &E,
+    output: &mut Vec<O>,
+) where
+    CachedKey<U, I>: Radixable<U>,
+    U: RadixKey,
+    I: PermIndex,
+    E: Fn(&mut Vec<O>, usize, Option<usize>),
+{
+    let mut i = 0;
+    let mut j = 0;
+    while i < left.len() {
+        let key = left[i].into_key_type();
+        let same_key = |c: &&CachedKey<U, I>| c.into_key_type() == key;
+        let i_end = i + left[i..].iter().take_while(same_key).count();
+        while j < right.len() && right[j].into_key_type() < key {
+            j += 1;
+        }
+        let j_end = j + right[j..].iter().take_while(same_key).count();
+        let matched = j < j_end;
+
+        for l in left[i..i_end].iter() {
+            let l = l.index.to_usize();
+            match kind {
+                Kind::Inner | Kind::Left if matched => {
+                    for r in right[j..j_end].iter() {
+                        emit(output, l, Some(r.index.to_usize()));
+                    }
+                },
+                Kind::Left => emit(output, l, None),
+                Kind::Semi if matched => emit(output, l, None),
+                Kind::Anti if !matched => emit(output, l, None),
+                _ => {},
+            }
+        }
+
+        i = i_end;
+        j = j_end;
+    }
+}
+
+fn join_aux<U, I, O, E>(
+    mut left_keys: Vec<CachedKey<U, I>>,
+    mut right_keys: Vec<CachedKey<U, I>>,
+    kind: Kind,
+    thread_n: Option<usize>,
+    emit: &E,
+) -> Vec<O>
+where
+    CachedKey<U, I>: Radixable<U>,
+    U: RadixKey,
+    I: PermIndex,
+    O: Send,
+    E: Fn(&mut Vec<O>, usize, Option<usize>) + Sync,
+{
+    let buckets = co_partition(&mut left_keys, &mut right_keys);
+
+    let join_bucket = |(l, r): Bucket<U, I>| {
+        l.voracious_stable_sort();
+        r.voracious_stable_sort();
+        let mut output = Vec::new();
+        merge_join(l, r, kind, emit, &mut output);
+        output
+    };
+
+    let outputs: Vec<Vec<O>> = match thread_n {
+        None => buckets.into_iter().map(join_bucket).collect(),
+        Some(thread_n) => {
+            let pool =
+                ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+            pool.install(|| buckets.into_par_iter().map(join_bucket).collect())
+        },
+    };
+
+    outputs.into_iter().flatten().collect()
+}
+
+// Joins the keys extracted from both sides.
+struct Join<'a, L, R, K, FL, FR, O, E> {
+    left: &'a [L],
+    right: &'a [R],
+    left_key: &'a FL,
+    right_key: &'a FR,
+    kind: Kind,
+    thread_n: Option<usize>,
+    emit: &'a E,
+    types: PhantomData<fn() -> (K, O)>,
+}
+
+impl<'a, L, R, K, FL, FR, O, E> CachedKeyTask
+    for Join<'a, L, R, K, FL, FR, O, E>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+    O: Send,
+    E: Fn(&mut Vec<O>, usize, Option<usize>) + Sync,
+{
+    type Output = Vec<O>;
+
+    fn run<U, I>(self, narrow: fn(u128) -> U) -> Vec<O>
+    where
+        CachedKey<U, I>: Radixable<U>,
+        U: RadixKey,
+        I: PermIndex,
+    {
+        let l = extract_keys(self.left, self.left_key, narrow);
+        let r = extract_keys(self.right, self.right_key, narrow);
+        join_aux(l, r, self.kind, self.thread_n, self.emit)
+    }
+}
+
+fn join<L, R, K, FL, FR, O, E>(
+    left: &[L],
+    right: &[R],
+    (left_key, right_key): (FL, FR),
+    kind: Kind,
+    thread_n: Option<usize>,
+    emit: E,
+) -> Vec<O>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+    O: Send,
+    E: Fn(&mut Vec<O>, usize, Option<usize>) + Sync,
+{
+    let bits = size_of::<K::Key>() * 8;
+    let len = left.len().max(right.len());
+    let task = Join {
+        left,
+        right,
+        left_key: &left_key,
+        right_key: &right_key,
+        kind,
+        thread_n,
+        emit: &emit,
+        types: PhantomData,
+    };
+    run_cached_key_task(bits, len, task)
+}
+
+fn emit_pair(output: &mut Vec<(usize, usize)>, l: usize, r: Option<usize>) {
+    output.push((l, r.unwrap()));
+}
+
+fn emit_left(
+    output: &mut Vec<(usize, Option<usize>)>,
+    l: usize,
+    r: Option<usize>,
+) {
+    output.push((l, r));
+}
+
+fn emit_index(output: &mut Vec<usize>, l: usize, _r: Option<usize>) {
+    output.push(l);
+}
+
+/// # Radix join
+///
+/// Inner join of two slices on a radix key: return the pairs of indices
+/// `(i, j)` such that `left_key(&left[i]) == right_key(&right[j])`.
+///
+/// The keys are extracted once into (key, index) pairs. Both sides are
+/// partitioned on the same most significant digits, with the histograms of
+/// the MSD sort, until each pair of buckets is small enough to fit in cache.
+/// The buckets are then sorted and merge joined.
+///
+/// The pairs are sorted by key, then by left index and by right index.
+///
+/// The keys are compared by their radix keys: for floats, `0.0` and `-0.0`
+/// are different keys, and a NaN only matches a NaN with the same bits.
+///
+/// ```
+/// use voracious_radix_sort::radix_join;
+///
+/// let orders = vec![(10_u64, "pen"), (20, "ink"), (10, "pad")];
+/// let users = vec![(20_u64, "bob"), (10, "ann"), (30, "eve")];
+/// let pairs = radix_join(&orders, &users, |o| o.0, |u| u.0);
+/// assert_eq!(pairs, vec![(0, 1), (2, 1), (1, 0)]);
+/// ```
+pub fn radix_join<L, R, K, FL, FR>(
+    left: &[L],
+    right: &[R],
+    left_key: FL,
+    right_key: FR,
+) -> Vec<(usize, usize)>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+{
+    join(left, right, (left_key, right_key), Kind::Inner, None, emit_pair)
+}
+
+/// # Radix left join
+///
+/// Same as the Radix join, the items of `left` without a match are also
+/// returned, paired with `None`.
+pub fn radix_left_join<L, R, K, FL, FR>(
+    left: &[L],
+    right: &[R],
+    left_key: FL,
+    right_key: FR,
+) -> Vec<(usize, Option<usize>)>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+{
+    join(left, right, (left_key, right_key), Kind::Left, None, emit_left)
+}
+
+/// # Radix semi join
+///
+/// Return the indices of the items of `left` which have at least one match
+/// in `right`, each index once.
+pub fn radix_semi_join<L, R, K, FL, FR>(
+    left: &[L],
+    right: &[R],
+    left_key: FL,
+    right_key: FR,
+) -> Vec<usize>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+{
+    join(left, right, (left_key, right_key), Kind::Semi, None, emit_index)
+}
+
+/// # Radix anti join
+///
+/// Return the indices of the items of `left` which have no match in `right`.
+pub fn radix_anti_join<L, R, K, FL, FR>(
+    left: &[L],
+    right: &[R],
+    left_key: FL,
+    right_key: FR,
+) -> Vec<usize>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+{
+    join(left, right, (left_key, right_key), Kind::Anti, None, emit_index)
+}
+
+/// # Radix join multithread
+///
+/// Same as the Radix join, the pairs of buckets are sorted and joined in
+/// parallel. The keys are extracted with a single thread.
+pub fn radix_join_mt<L, R, K, FL, FR>(
+    left: &[L],
+    right: &[R],
+    left_key: FL,
+    right_key: FR,
+    thread_n: usize,
+) -> Vec<(usize, usize)>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+{
+    let keys = (left_key, right_key);
+    join(left, right, keys, Kind::Inner, Some(thread_n), emit_pair)
+}
+
+/// # Radix left join multithread
+///
+/// Same as the Radix left join, the pairs of buckets are sorted and joined in
+/// parallel.
+pub fn radix_left_join_mt<L, R, K, FL, FR>(
+    left: &[L],
+    right: &[R],
+    left_key: FL,
+    right_key: FR,
+    thread_n: usize,
+) -> Vec<(usize, Option<usize>)>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+{
+    let keys = (left_key, right_key);
+    join(left, right, keys, Kind::Left, Some(thread_n), emit_left)
+}
+
+/// # Radix semi join multithread
+///
+/// Same as the Radix semi join, the pairs of buckets are sorted and joined in
+/// parallel.
+pub fn radix_semi_join_mt<L, R, K, FL, FR>(
+    left: &[L],
+    right: &[R],
+    left_key: FL,
+    right_key: FR,
+    thread_n: usize,
+) -> Vec<usize>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+{
+    let keys = (left_key, right_key);
+    join(left, right, keys, Kind::Semi, Some(thread_n), emit_index)
+}
+
+/// # Radix anti join multithread
+///
+/// Same as the Radix anti join, the pairs of buckets are sorted and joined in
+/// parallel.
+pub fn radix_anti_join_mt<L, R, K, FL, FR>(
+    left: &[L],
+    right: &[R],
+    left_key: FL,
+    right_key: FR,
+    thread_n: usize,
+) -> Vec<usize>
+where
+    K: RadixKey,
+    FL: Fn(&L) -> K,
+    FR: Fn(&R) -> K,
+{
+    let keys = (left_key, right_key);
+    join(left, right, keys, Kind::Anti, Some(thread_n), emit_index)
+}
@@ -0,0 +1,142 @@
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+use super::super::{RadixKey, RadixSort, Radixable};
+use super::k_way_merge::merge2;
+
+/// # Radix sorted vector
+///
+/// A vector which keeps its elements sorted.
+///
+/// Bulk insertions (`extend`, `extend_from_slice`) sort the new batch with
+/// the Voracious sort and merge it with the elements already in the vector.
+/// The merge is stable: the elements already in the vector come before the
+/// equal elements of the new batch.
+///
+/// The elements are stored contiguously, it is a cache friendly alternative
+/// to a `BTreeSet` for append heavy data. Duplicates are allowed.
+///
+/// ```
+/// use voracious_radix_sort::RadixSortedVec;
+///
+/// let mut v = RadixSortedVec::new();
+/// v.extend_from_slice(&[7, 1, 5]);
+/// v.extend(vec![4, 9, 2]);
+/// assert_eq!(v.as_slice(), &[1, 2, 4, 5, 7, 9]);
+/// assert_eq!(v.range(2..7), &[2, 4, 5]);
+/// assert_eq!(v.rank(&5), 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RadixSortedVec<T: Radixable<K>, K: RadixKey> {
+    data: Vec<T>,
+    _key: PhantomData<K>,
+}
+
+impl<T: Radixable<K>, K: RadixKey> Default for RadixSortedVec<T, K> {
+    fn default() -> Self {
+        RadixSortedVec { data: Vec::new(), _key: PhantomData }
+    }
+}
+
+impl<T: Radixable<K>, K: RadixKey> From<Vec<T>> for RadixSortedVec<T, K> {
+    fn from(mut data: Vec<T>) -> Self {
+        data.as_mut_slice().voracious_sort();
+        RadixSortedVec { data, _key: PhantomData }
+    }
+}
+
+impl<T: Radixable<K>, K: RadixKey> Extend<T> for RadixSortedVec<T, K> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let batch: Vec<T> = iter.into_iter().collect();
+        self.merge_batch(batch);
+    }
+}
+
+impl<T: Radixable<K>, K: RadixKey> RadixSortedVec<T, K> {
+    pub fn new() -> Self { RadixSortedVec::default() }
+
+    pub fn len(&self) -> usize { self.data.len() }
+
+    pub fn is_empty(&self) -> bool { self.data.is_empty() }
+
+    pub fn as_slice(&self) -> &[T] { &self.data }
+
+    pub fn into_vec(self) -> Vec<T> { self.data }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> { self.data.iter() }
+
+    /// Insert one element after the elements equal to it.
+    pub fn insert(&mut self, item: T) {
+        let index = self.upper_bound(&item);
+        self.data.insert(index, item);
+    }
+
+    /// Sort the batch and merge it with the elements of the vector.
+    pub fn extend_from_slice(&mut self, batch: &[T]) {
+        self.merge_batch(batch.to_vec());
+    }
+
+    /// Index of the first element which is not less than `value`.
+    pub fn lower_bound(&self, value: &T) -> usize {
+        self.data.partition_point(|item| item < value)
+    }
+
+    /// Index of the first element which is greater than `value`.
+    pub fn upper_bound(&self, value: &T) -> usize {
+        self.data.partition_point(|item| item <= value)
+    }
+
+    /// Number of elements less than `value`.
+    pub fn rank(&self, value: &T) -> usize { self.lower_bound(value) }
+
+    /// Elements within the range of values.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> &[T] {
+        let (start, end) = self.range_indices(&range);
+        &self.data[start..end]
+    }
+
+    /// Remove the elements within the range of values, and return them.
+    pub fn remove_range<R: RangeBounds<T>>(&mut self, range: R) -> Vec<T> {
+        let (start, end) = self.range_indices(&range);
+        self.data.drain(start..end).collect()
+    }
+
+    /// Keep only the elements for which `f` returns `true`.
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) {
+        self.data.retain(f);
+    }
+
+    fn range_indices<R: RangeBounds<T>>(&self, range: &R) -> (usize, usize) {
+        let start = match range.start_bound() {
+            Bound::Included(value) => self.lower_bound(value),
+            Bound::Excluded(value) => self.upper_bound(value),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(value) => self.upper_bound(value),
+            Bound::Excluded(value) => self.lower_bound(value),
+            Bound::Unbounded => self.data.len(),
+        };
+
+        (start, end.max(start))
+    }
+
+    fn merge_batch(&mut self, mut batch: Vec<T>) {
+        if batch.is_empty() {
+            return;
+        }
+
+        batch.as_mut_slice().voracious_sort();
+
+        if self.data.is_empty() {
+            self.data = batch;
+            return;
+        }
+
+        let middle = self.data.len();
+        self.data.extend_from_slice(&batch);
+        let end = self.data.len();
+        let mut copy = vec![self.data[0]; middle.min(end - middle)];
+        merge2(&mut self.data, &mut copy, 0, middle, end);
+    }
+}
@@ -0,0 +1,256 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use super::super::sorts::american_flag_sort::american_flag_sort;
+use super::super::sorts::dlsd_sort::dlsd_radixsort;
+use super::super::sorts::lsd_sort::lsd_radixsort;
+use super::super::sorts::lsd_stable_sort::lsd_stable_radixsort;
+use super::super::sorts::msd_sort::msd_radixsort;
+use super::super::sorts::msd_stable_sort::msd_stable_radixsort;
+use super::super::sorts::peeka_sort::peeka_sort;
+use super::super::sorts::rollercoaster_sort::rollercoaster_sort;
+use super::super::sorts::ska_sort::ska_sort;
+use super::super::sorts::thiel_sort::thiel_radixsort;
+use super::super::sorts::voracious_sort::voracious_sort;
+use super::super::{RadixKey, Radixable};
+
+const MAX_RADIX: usize = 16;
+
+/// # Algorithm
+///
+/// The raw sorts of the crate which take a radix, to be run by `sort_with`.
+///
+/// Each algorithm describes its properties, and the radix values it sorts
+/// correctly for a key of a given number of bits.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    AmericanFlag,
+    Dlsd,
+    Lsd,
+    LsdStable,
+    Msd,
+    MsdStable,
+    Peeka,
+    Rollercoaster,
+    Ska,
+    Thiel,
+    Voracious,
+}
+
+impl Algorithm {
+    /// Every registered algorithm.
+    pub const ALL: [Algorithm; 11] = [
+        Algorithm::AmericanFlag,
+        Algorithm::Dlsd,
+        Algorithm::Lsd,
+        Algorithm::LsdStable,
+        Algorithm::Msd,
+        Algorithm::MsdStable,
+        Algorithm::Peeka,
+        Algorithm::Rollercoaster,
+        Algorithm::Ska,
+        Algorithm::Thiel,
+        Algorithm::Voracious,
+    ];
+
+    /// Name of the raw sort function.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Algorithm::AmericanFlag => "american_flag_sort",
+            Algorithm::Dlsd => "dlsd_radixsort",
+            Algorithm::Lsd => "lsd_radixsort",
+            Algorithm::LsdStable => "lsd_stable_radixsort",
+            Algorithm::Msd => "msd_radixsort",
+            Algorithm::MsdStable => "msd_stable_radixsort",
+            Algorithm::Peeka => "peeka_sort",
+            Algorithm::Rollercoaster => "rollercoaster_sort",
+            Algorithm::Ska => "ska_sort",
+            Algorithm::Thiel => "thiel_radixsort",
+            Algorithm::Voracious => "voracious_sort",
+        }
+    }
+
+    pub fn is_stable(&self) -> bool {
+        matches!(self, Algorithm::LsdStable | Algorithm::MsdStable)
+    }
+
+    /// The in place sorts do not allocate a buffer of the size of the array.
+    pub fn is_in_place(&self) -> bool {
+        matches!(
+            self,
+            Algorithm::AmericanFlag
+                | Algorithm::Peeka
+                | Algorithm::Ska
+                | Algorithm::Voracious
+        )
+    }
+
+    /// The multithread sorts use `thread_n` and `block_size`.
+    pub fn is_multithread(&self) -> bool {
+        *self == Algorithm::Peeka
+    }
+
+    /// Radix values sorted correctly for a key of `bits` bits.
+    ///
+    /// - The MSD sorts can not have a digit wider than the key.
+    /// - The LSD sorts, and the sorts which fallback on the LSD sort, need a
+    ///   radix of at least 8.
+    /// - The Thiel sort handles at most 8 digits.
+    pub fn radix_range(&self, bits: usize) -> RangeInclusive<usize> {
+        let msd_max = bits.min(MAX_RADIX);
+        match self {
+            Algorithm::AmericanFlag
+            | Algorithm::Msd
+            | Algorithm::MsdStable
+            | Algorithm::Ska
+            | Algorithm::Voracious => 1..=msd_max,
+            Algorithm::Peeka | Algorithm::Rollercoaster => 8..=msd_max,
+            Algorithm::Dlsd => 1..=MAX_RADIX,
+            Algorithm::Lsd | Algorithm::LsdStable => 8..=MAX_RADIX,
+            Algorithm::Thiel => ((bits.max(1) - 1) / 8 + 1)..=MAX_RADIX,
+        }
+    }
+}
+
+/// # Sort params
+///
+/// Parameters of `sort_with`. `block_size` and `thread_n` are only used by
+/// the multithread sorts.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SortParams {
+    pub radix: usize,
+    pub block_size: usize,
+    pub thread_n: usize,
+}
+
+impl Default for SortParams {
+    fn default() -> SortParams {
+        SortParams { radix: 8, block_size: 650_000, thread_n: 1 }
+    }
+}
+
+/// # Sort error
+///
+/// Error returned by `sort_with` when the params are not valid.
+///
+/// - `Radix`: the radix is not in the range of the algorithm, for the key.
+/// - `BlockSize`: the block size of a multithread sort must be positive.
+/// - `ThreadCount`: a multithread sort needs at least one thread.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SortError {
+    Radix { algorithm: Algorithm, radix: usize, min: usize, max: usize },
+    BlockSize { block_size: usize },
+    ThreadCount { thread_n: usize },
+}
+
+impl fmt::Display for SortError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SortError::Radix { algorithm, radix, min, max } => write!(
+                f,
+                "[sort_with] Invalid radix {} for {}: the radix must be \
+                 between {} and {} for this key.",
+                radix,
+                algorithm.name(),
+                min,
+                max
+            ),
+            SortError::BlockSize { block_size } => write!(
+                f,
+                "[sort_with] Invalid block size {}: it must be positive.",
+                block_size
+            ),
+            SortError::ThreadCount { thread_n } => write!(
+                f,
+                "[sort_with] Invalid number of threads {}: it must be \
+                 positive.",
+                thread_n
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SortError {}
+
+fn check_params(
+    algorithm: Algorithm,
+    params: &SortParams,
+    range: RangeInclusive<usize>,
+) -> Result<(), SortError> {
+    if !range.contains(&params.radix) {
+        return Err(SortError::Radix {
+            algorithm,
+            radix: params.radix,
+            min: *range.start(),
+            max: *range.end(),
+        });
+    }
+    if algorithm.is_multithread() {
+        if params.block_size == 0 {
+            return Err(SortError::BlockSize { block_size: 0 });
+        }
+        if params.thread_n == 0 {
+            return Err(SortError::ThreadCount { thread_n: 0 });
+        }
+    }
+
+    Ok(())
+}
+
+/// # Sort with
+///
+/// Sort the array with the given raw sort, after checking the params against
+/// the properties of the algorithm and the number of bits of the key. Unlike
+/// the raw sorts, an invalid radix is reported instead of giving an unsorted
+/// array. The array is left untouched on error.
+///
+/// The number of bits of the key is given by the first item, thus an empty
+/// array only needs a radix between 1 and 16.
+///
+/// ```
+/// use voracious_radix_sort::{sort_with, Algorithm, SortError, SortParams};
+///
+/// let mut arr: Vec<u8> = vec![5, 3, 200, 1];
+/// let params = SortParams { radix: 4, ..SortParams::default() };
+/// sort_with(&mut arr, Algorithm::Msd, params).unwrap();
+/// assert_eq!(arr, vec![1, 3, 5, 200]);
+///
+/// // The digit is wider than the key.
+/// let params = SortParams { radix: 12, ..SortParams::default() };
+/// let error = sort_with(&mut arr, Algorithm::Msd, params).unwrap_err();
+/// assert!(matches!(error, SortError::Radix { min: 1, max: 8, .. }));
+/// ```
+pub fn sort_with<T, K>(
+    arr: &mut [T],
+    algorithm: Algorithm,
+    params: SortParams,
+) -> Result<(), SortError>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let range = match arr.first() {
+        Some(dummy) => algorithm.radix_range(dummy.type_size()),
+        None => 1..=MAX_RADIX,
+    };
+    check_params(algorithm, &params, range)?;
+
+    let radix = params.radix;
+    match algorithm {
+        Algorithm::AmericanFlag => american_flag_sort(arr, radix),
+        Algorithm::Dlsd => dlsd_radixsort(arr, radix),
+        Algorithm::Lsd => lsd_radixsort(arr, radix),
+        Algorithm::LsdStable => lsd_stable_radixsort(arr, radix),
+        Algorithm::Msd => msd_radixsort(arr, radix),
+        Algorithm::MsdStable => msd_stable_radixsort(arr, radix),
+        Algorithm::Peeka => {
+            peeka_sort(arr, radix, params.block_size, params.thread_n)
+        },
+        Algorithm::Rollercoaster => rollercoaster_sort(arr, radix),
+        Algorithm::Ska => ska_sort(arr, radix),
+        Algorithm::Thiel => thiel_radixsort(arr, radix),
+        Algorithm::Voracious => voracious_sort(arr, radix),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use super::super::{RadixKey, RadixSort, Radixable};
+use super::k_way_merge::{k_way_merge, merge2};
+
+/// # Streaming sorter
+///
+/// Sort data received in batches.
+///
+/// Each batch is sorted with the Voracious sort when it is pushed, and kept
+/// as a sorted run. Like in a LSM tree, the runs are coalesced by tiers: the
+/// last two runs are merged while the last run is at least half as big as the
+/// previous one. Thus there are at most `log2(n)` runs, and each merge makes
+/// the run of an item at least 1.5 times bigger, so the total work stays in
+/// `O(n log(batches))`.
+///
+/// `snapshot()` merges the runs into a new vector without consuming the
+/// sorter, `finish()` merges the runs and consumes the sorter.
+///
+/// The batches are sorted with an unstable sort, the merges are stable.
+///
+/// ```
+/// use voracious_radix_sort::StreamingSorter;
+///
+/// let mut sorter = StreamingSorter::new();
+/// sorter.push(&[5, 1, 4]);
+/// sorter.push(&[3, 2]);
+/// assert_eq!(sorter.snapshot(), vec![1, 2, 3, 4, 5]);
+/// sorter.push(&[0]);
+/// assert_eq!(sorter.finish(), vec![0, 1, 2, 3, 4, 5]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct StreamingSorter<T> {
+    runs: Vec<Vec<T>>,
+    len: usize,
+}
+
+impl<T> Default for StreamingSorter<T> {
+    fn default() -> Self { StreamingSorter { runs: Vec::new(), len: 0 } }
+}
+
+impl<T: Copy + PartialOrd> StreamingSorter<T> {
+    pub fn new() -> Self { StreamingSorter::default() }
+
+    /// Number of items pushed so far.
+    pub fn len(&self) -> usize { self.len }
+
+    pub fn is_empty(&self) -> bool { self.len == 0 }
+
+    /// Sort the batch and add it as a new run.
+    pub fn push<K>(&mut self, batch: &[T])
+    where
+        T: Radixable<K>,
+        K: RadixKey,
+    {
+        if batch.is_empty() {
+            return;
+        }
+
+        let mut run = batch.to_vec();
+        run.as_mut_slice().voracious_sort();
+        self.len += run.len();
+        self.runs.push(run);
+
+        self.coalesce();
+    }
+
+    /// Merge all the runs into a sorted vector, the sorter is kept as is.
+    pub fn snapshot(&self) -> Vec<T> {
+        let mut arr = Vec::with_capacity(self.len);
+        let mut separators = vec![0];
+        for run in self.runs.iter() {
+            arr.extend_from_slice(run);
+            separators.push(arr.len());
+        }
+        k_way_merge(&mut arr, &mut separators);
+        arr
+    }
+
+    /// Merge all the runs into a sorted vector.
+    pub fn finish(mut self) -> Vec<T> {
+        if self.runs.len() == 1 {
+            return self.runs.pop().unwrap();
+        }
+        self.snapshot()
+    }
+
+    fn coalesce(&mut self) {
+        while self.runs.len() >= 2 {
+            let n = self.runs.len();
+            if self.runs[n - 2].len() > 2 * self.runs[n - 1].len() {
+                break;
+            }
+
+            let last = self.runs.pop().unwrap();
+            let run = self.runs.last_mut().unwrap();
+            let middle = run.len();
+            run.extend_from_slice(&last);
+            let end = run.len();
+            let mut copy = vec![run[0]; middle.min(end - middle)];
+            merge2(run, &mut copy, 0, middle, end);
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn run_count(&self) -> usize { self.runs.len() }
+}
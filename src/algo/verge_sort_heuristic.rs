@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+
 use super::super::{RadixKey, Radixable};
 
 #[derive(PartialEq, Debug)]
@@ -597,6 +599,25 @@ pub fn explore_around<T: PartialOrd>(
     ((b_pattern, bp1, bp2), (f_pattern, fp1, fp2))
 }
 
+// A descending run is not strictly descending: in a stable sort, each
+// plateau is reversed back so that the equal items keep their order.
+#[inline]
+fn reverse_run<T: PartialOrd>(arr: &mut [T], stable: bool) {
+    arr.reverse();
+
+    if stable {
+        let mut start = 0;
+        for i in 1..=arr.len() {
+            if i == arr.len()
+                || arr[i].partial_cmp(&arr[start]) != Some(Ordering::Equal)
+            {
+                arr[start..i].reverse();
+                start = i;
+            }
+        }
+    }
+}
+
 #[inline]
 fn handle_asc_then_desc<T, K>(
     arr: &mut [T],
@@ -607,8 +628,8 @@ fn handle_asc_then_desc<T, K>(
     last_sorted: usize,
     big_enough: usize,
     separators: &mut Vec<usize>,
-    radix: usize,
-    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
+    fallback_sort: &dyn Fn(&mut [T]),
+    stable: bool,
 ) -> (usize, usize)
 where
     T: Radixable<K> + Copy + PartialOrd,
@@ -622,7 +643,7 @@ where
         && (bp2 - last_sorted > big_enough || bp2 - last_sorted == 0)
     {
         if bp2 - last_sorted > 0 {
-            fallback_sort(&mut arr[last_sorted..bp2], radix);
+            fallback_sort(&mut arr[last_sorted..bp2]);
             separators.push(bp2);
         }
 
@@ -631,12 +652,12 @@ where
     } else if fp2 - bp1 >= big_enough {
         if bp1 - last_sorted >= big_enough || bp1 - last_sorted == 0 {
             if bp1 - last_sorted > 0 {
-                fallback_sort(&mut arr[last_sorted..bp1], radix);
+                fallback_sort(&mut arr[last_sorted..bp1]);
                 separators.push(bp1);
             }
 
             separators.push(fp2);
-            arr[bp1..fp2].reverse();
+            reverse_run(&mut arr[bp1..fp2], stable);
             (fp2, fp2)
         } else {
             (fp2, last_sorted)
@@ -656,8 +677,8 @@ fn handle_desc_then_asc<T, K>(
     last_sorted: usize,
     big_enough: usize,
     separators: &mut Vec<usize>,
-    radix: usize,
-    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
+    fallback_sort: &dyn Fn(&mut [T]),
+    stable: bool,
 ) -> (usize, usize)
 where
     T: Radixable<K> + Copy + PartialOrd,
@@ -671,19 +692,19 @@ where
         if bp1 - bp2 >= big_enough || bp1 - bp2 == 0 {
             if bp2 - last_sorted >= big_enough || bp2 - last_sorted == 0 {
                 if bp2 - last_sorted > 0 {
-                    fallback_sort(&mut arr[last_sorted..bp2], radix);
+                    fallback_sort(&mut arr[last_sorted..bp2]);
                     separators.push(bp2);
                 }
 
                 if bp1 - bp2 > 0 {
                     separators.push(bp1);
-                    arr[bp2..bp1].reverse();
+                    reverse_run(&mut arr[bp2..bp1], stable);
                 }
 
                 separators.push(fp2);
                 (fp2, fp2)
             } else {
-                fallback_sort(&mut arr[last_sorted..bp1], radix);
+                fallback_sort(&mut arr[last_sorted..bp1]);
                 separators.push(bp1);
 
                 separators.push(fp2);
@@ -691,7 +712,7 @@ where
             }
         } else if bp1 - last_sorted >= big_enough || bp1 - last_sorted == 0 {
             if bp1 - last_sorted > 0 {
-                fallback_sort(&mut arr[last_sorted..bp1], radix);
+                fallback_sort(&mut arr[last_sorted..bp1]);
                 separators.push(bp1);
             }
 
@@ -703,12 +724,12 @@ where
     } else if fp1 - bp2 >= big_enough {
         if bp2 - last_sorted >= big_enough || bp2 - last_sorted == 0 {
             if bp2 - last_sorted > 0 {
-                fallback_sort(&mut arr[last_sorted..bp2], radix);
+                fallback_sort(&mut arr[last_sorted..bp2]);
                 separators.push(bp2);
             }
 
             separators.push(fp1);
-            arr[bp2..fp1].reverse();
+            reverse_run(&mut arr[bp2..fp1], stable);
 
             (fp1, fp1)
         } else {
@@ -731,8 +752,8 @@ fn handle_part<T, K>(
     last_sorted: usize,
     big_enough: usize,
     separators: &mut Vec<usize>,
-    radix: usize,
-    fallback_sort: &dyn Fn(&mut [T], usize) -> (),
+    fallback_sort: &dyn Fn(&mut [T]),
+    stable: bool,
 ) -> (usize, usize)
 where
     T: Radixable<K> + Copy + PartialOrd,
@@ -748,8 +769,8 @@ where
             last_sorted,
             big_enough,
             separators,
-            radix,
             fallback_sort,
+            stable,
         ),
         GrowthPattern::DescThenAsc => handle_desc_then_asc(
             arr,
@@ -760,14 +781,14 @@ where
             last_sorted,
             big_enough,
             separators,
-            radix,
             fallback_sort,
+            stable,
         ),
         GrowthPattern::AscOnly | GrowthPattern::PlateauOnly => {
             if fp2 - bp2 >= big_enough {
                 if bp2 - last_sorted >= big_enough || bp2 - last_sorted == 0 {
                     if bp2 - last_sorted > 0 {
-                        fallback_sort(&mut arr[last_sorted..bp2], radix);
+                        fallback_sort(&mut arr[last_sorted..bp2]);
                         separators.push(bp2);
                     }
 
@@ -784,12 +805,12 @@ where
             if fp2 - bp2 >= big_enough {
                 if bp2 - last_sorted >= big_enough || bp2 - last_sorted == 0 {
                     if bp2 - last_sorted > 0 {
-                        fallback_sort(&mut arr[last_sorted..bp2], radix);
+                        fallback_sort(&mut arr[last_sorted..bp2]);
                         separators.push(bp2);
                     }
 
                     separators.push(fp2);
-                    arr[bp2..fp2].reverse();
+                    reverse_run(&mut arr[bp2..fp2], stable);
                     (fp2, fp2)
                 } else {
                     (fp2, last_sorted)
@@ -813,6 +834,34 @@ where
     T: Radixable<K> + Copy + PartialOrd,
     K: RadixKey,
 {
+    verge_sort_preprocessing_aux(arr, radix, fallback_sort, false)
+}
+
+// Same as the Verge sort pre-processing, but the equal items of a reversed
+// descending run keep their order. The fallback sort must be stable too.
+pub fn verge_sort_preprocessing_stable<T, K>(
+    arr: &mut [T],
+    radix: usize,
+    fallback_sort: &dyn Fn(&mut [T], usize),
+) -> Vec<usize>
+where
+    T: Radixable<K> + Copy + PartialOrd,
+    K: RadixKey,
+{
+    verge_sort_preprocessing_aux(arr, radix, fallback_sort, true)
+}
+
+fn verge_sort_preprocessing_aux<T, K>(
+    arr: &mut [T],
+    radix: usize,
+    fallback_sort: &dyn Fn(&mut [T], usize),
+    stable: bool,
+) -> Vec<usize>
+where
+    T: Radixable<K> + Copy + PartialOrd,
+    K: RadixKey,
+{
+    let fallback = |arr: &mut [T]| fallback_sort(arr, radix);
     let size = arr.len();
     let big_enough = compute_big_enough_run(size);
     let mut last_sorted = 0;
@@ -834,8 +883,8 @@ where
             last_sorted,
             big_enough,
             &mut separators,
-            radix,
-            fallback_sort,
+            &fallback,
+            stable,
         );
 
         last_sorted = new_last_sorted;
@@ -0,0 +1,55 @@
+use rand::{thread_rng, Rng};
+use rayon::prelude::*;
+
+use super::super::types::half::{BF16, F16};
+
+// Uniform, all the bit patterns, NaNs included
+pub fn helper_random_array_uniform_f16(size: usize) -> Vec<F16> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| F16(thread_rng().gen::<u16>()))
+        .collect::<Vec<F16>>()
+}
+
+// All the bit patterns, descending
+pub fn helper_random_array_descending_f16(size: usize) -> Vec<F16> {
+    (0..size)
+        .into_par_iter()
+        .map(|i| F16((size - i) as u16))
+        .collect::<Vec<F16>>()
+}
+
+pub fn generators_f16() -> Vec<(&'static dyn Fn(usize) -> Vec<F16>, &'static str)>
+{
+    vec![
+        (&helper_random_array_uniform_f16, "-- Unif       :"),
+        (&helper_random_array_descending_f16, "-- Descending :"),
+    ]
+}
+
+// Uniform, all the bit patterns, NaNs included
+pub fn helper_random_array_uniform_bf16(size: usize) -> Vec<BF16> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| BF16(thread_rng().gen::<u16>()))
+        .collect::<Vec<BF16>>()
+}
+
+// Truncated f32 in [-1000, 1000]
+pub fn helper_random_array_small_bf16(size: usize) -> Vec<BF16> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let v: f32 = thread_rng().gen_range(-1000.0, 1000.0);
+            BF16((v.to_bits() >> 16) as u16)
+        })
+        .collect::<Vec<BF16>>()
+}
+
+pub fn generators_bf16(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<BF16>, &'static str)> {
+    vec![
+        (&helper_random_array_uniform_bf16, "-- Unif       :"),
+        (&helper_random_array_small_bf16, "-- Small      :"),
+    ]
+}
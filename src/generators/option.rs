@@ -0,0 +1,94 @@
+use rand::{thread_rng, Rng};
+use rayon::prelude::*;
+
+use super::super::types::option::NoneLast;
+
+// Uniform, with about 1/4 of None
+pub fn helper_random_array_uniform_option_u32(size: usize) -> Vec<Option<u32>> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            if rng.gen_range(0, 4) == 0 {
+                None
+            } else {
+                Some(rng.gen::<u32>())
+            }
+        })
+        .collect::<Vec<Option<u32>>>()
+}
+
+// Only None
+pub fn helper_random_array_none_option_u32(size: usize) -> Vec<Option<u32>> {
+    vec![None; size]
+}
+
+pub fn generators_option_u32(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Option<u32>>, &'static str)> {
+    vec![
+        (&helper_random_array_uniform_option_u32, "-- Unif       :"),
+        (&helper_random_array_none_option_u32, "-- None       :"),
+    ]
+}
+
+// Uniform, with about 1/4 of None
+pub fn helper_random_array_uniform_option_i64(size: usize) -> Vec<Option<i64>> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            if rng.gen_range(0, 4) == 0 {
+                None
+            } else {
+                Some(rng.gen::<i64>())
+            }
+        })
+        .collect::<Vec<Option<i64>>>()
+}
+
+pub fn generators_option_i64(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Option<i64>>, &'static str)> {
+    vec![(&helper_random_array_uniform_option_i64, "-- Unif       :")]
+}
+
+// Uniform, with about 1/4 of None
+pub fn helper_random_array_uniform_option_f64(size: usize) -> Vec<Option<f64>> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            if rng.gen_range(0, 4) == 0 {
+                None
+            } else {
+                Some(rng.gen_range(-1_000_000.0, 1_000_000.0))
+            }
+        })
+        .collect::<Vec<Option<f64>>>()
+}
+
+pub fn generators_option_f64(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Option<f64>>, &'static str)> {
+    vec![(&helper_random_array_uniform_option_f64, "-- +/-10^6   :")]
+}
+
+// Uniform, with about 1/4 of None
+pub fn helper_random_array_uniform_nonelast_u16(
+    size: usize,
+) -> Vec<NoneLast<u16>> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            if rng.gen_range(0, 4) == 0 {
+                NoneLast(None)
+            } else {
+                NoneLast(Some(rng.gen::<u16>()))
+            }
+        })
+        .collect::<Vec<NoneLast<u16>>>()
+}
+
+pub fn generators_nonelast_u16(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<NoneLast<u16>>, &'static str)> {
+    vec![(&helper_random_array_uniform_nonelast_u16, "-- Unif       :")]
+}
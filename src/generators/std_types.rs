@@ -0,0 +1,180 @@
+use std::cmp::Reverse;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::num::{NonZeroI32, NonZeroU64, Wrapping};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use rand::{thread_rng, Rng};
+use rayon::prelude::*;
+
+// Uniform, seconds on 40 bits
+pub fn helper_random_array_uniform_duration(size: usize) -> Vec<Duration> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            Duration::new(
+                rng.gen_range(0, 1u64 << 40),
+                rng.gen_range(0, 1_000_000_000),
+            )
+        })
+        .collect::<Vec<Duration>>()
+}
+
+// Few seconds, the nanoseconds matter
+pub fn helper_random_array_small_duration(size: usize) -> Vec<Duration> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            Duration::new(rng.gen_range(0, 4), rng.gen_range(0, 1_000_000_000))
+        })
+        .collect::<Vec<Duration>>()
+}
+
+pub fn generators_duration(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Duration>, &'static str)> {
+    vec![
+        (&helper_random_array_uniform_duration, "-- Unif       :"),
+        (&helper_random_array_small_duration, "-- Small      :"),
+    ]
+}
+
+// Uniform, before and after the epoch
+pub fn helper_random_array_uniform_systemtime(size: usize) -> Vec<SystemTime> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| {
+            let mut rng = thread_rng();
+            let d = Duration::new(
+                rng.gen_range(0, 1u64 << 32),
+                rng.gen_range(0, 1_000_000_000),
+            );
+            if rng.gen::<bool>() {
+                UNIX_EPOCH + d
+            } else {
+                UNIX_EPOCH - d
+            }
+        })
+        .collect::<Vec<SystemTime>>()
+}
+
+pub fn generators_systemtime(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<SystemTime>, &'static str)> {
+    vec![(&helper_random_array_uniform_systemtime, "-- Unif       :")]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_ipv4(size: usize) -> Vec<Ipv4Addr> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| Ipv4Addr::from(thread_rng().gen::<u32>()))
+        .collect::<Vec<Ipv4Addr>>()
+}
+
+pub fn generators_ipv4(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Ipv4Addr>, &'static str)> {
+    vec![(&helper_random_array_uniform_ipv4, "-- Unif       :")]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_ipv6(size: usize) -> Vec<Ipv6Addr> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| Ipv6Addr::from(thread_rng().gen::<u128>()))
+        .collect::<Vec<Ipv6Addr>>()
+}
+
+pub fn generators_ipv6(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Ipv6Addr>, &'static str)> {
+    vec![(&helper_random_array_uniform_ipv6, "-- Unif       :")]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_nonzero_u64(size: usize) -> Vec<NonZeroU64> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| NonZeroU64::new(thread_rng().gen_range(1, u64::MAX)).unwrap())
+        .collect::<Vec<NonZeroU64>>()
+}
+
+pub fn generators_nonzero_u64(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<NonZeroU64>, &'static str)> {
+    vec![(&helper_random_array_uniform_nonzero_u64, "-- Unif       :")]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_nonzero_i32(size: usize) -> Vec<NonZeroI32> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| loop {
+            if let Some(v) = NonZeroI32::new(thread_rng().gen::<i32>()) {
+                break v;
+            }
+        })
+        .collect::<Vec<NonZeroI32>>()
+}
+
+pub fn generators_nonzero_i32(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<NonZeroI32>, &'static str)> {
+    vec![(&helper_random_array_uniform_nonzero_i32, "-- Unif       :")]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_wrapping_i16(
+    size: usize,
+) -> Vec<Wrapping<i16>> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| Wrapping(thread_rng().gen::<i16>()))
+        .collect::<Vec<Wrapping<i16>>>()
+}
+
+pub fn generators_wrapping_i16(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Wrapping<i16>>, &'static str)> {
+    vec![(&helper_random_array_uniform_wrapping_i16, "-- Unif       :")]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_reverse_u32(
+    size: usize,
+) -> Vec<Reverse<u32>> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| Reverse(thread_rng().gen::<u32>()))
+        .collect::<Vec<Reverse<u32>>>()
+}
+
+pub fn generators_reverse_u32(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Reverse<u32>>, &'static str)> {
+    vec![(&helper_random_array_uniform_reverse_u32, "-- Unif       :")]
+}
+
+// Uniform
+pub fn helper_random_array_uniform_reverse_i64(
+    size: usize,
+) -> Vec<Reverse<i64>> {
+    (0..size)
+        .into_par_iter()
+        .map(|_| Reverse(thread_rng().gen::<i64>()))
+        .collect::<Vec<Reverse<i64>>>()
+}
+
+pub fn generators_reverse_i64(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Reverse<i64>>, &'static str)> {
+    vec![(&helper_random_array_uniform_reverse_i64, "-- Unif       :")]
+}
+
+// Uniform, seconds on 40 bits
+pub fn helper_random_array_uniform_reverse_duration(
+    size: usize,
+) -> Vec<Reverse<Duration>> {
+    helper_random_array_uniform_duration(size)
+        .into_iter()
+        .map(Reverse)
+        .collect::<Vec<Reverse<Duration>>>()
+}
+
+pub fn generators_reverse_duration(
+) -> Vec<(&'static dyn Fn(usize) -> Vec<Reverse<Duration>>, &'static str)> {
+    vec![(&helper_random_array_uniform_reverse_duration, "-- Unif       :")]
+}
@@ -0,0 +1,309 @@
+//! # Kernels
+//!
+//! The building blocks of the radix sorts of this crate, to build new radix
+//! sorts without forking it.
+//!
+//! The digits are described by validated `Params`: the radix (number of bits
+//! per digit), the offset (number of most significant bits skipped) and the
+//! level (index of the digit, from the most significant one).
+//!
+//! - `digit`, `histogram`, `ska_swap` and `copy_by_histogram` work on the
+//!   digit of the current level, counted from the offset (the digits of the
+//!   MSD sorts).
+//! - `full_histograms` gives the histograms of every level in one pass,
+//!   counted from the least significant bit (the digits of the LSD sorts).
+//! - `prefix_sums` gives the boundaries, the heads and the tails of the
+//!   buckets of a histogram.
+//! - `compute_offset` gives the offset of an array.
+//! - `RegionsGraph` is the graph of the regions to swap between the blocks of
+//!   an array, used by the Peeka sort.
+//!
+//! The functions check their inputs and panic on an invalid histogram, they
+//! never read or write out of the arrays.
+//!
+//! The kernels follow semantic versioning: their signatures only change with
+//! a new major version of the crate.
+//!
+//! ```
+//! use voracious_radix_sort::kernels::{copy_by_histogram, histogram, Params};
+//!
+//! // One MSD pass on the 8 most significant bits.
+//! let arr: Vec<u32> = vec![0x0300_0000, 0x0100_0001, 0x0100_0000, 7];
+//! let p = Params::new(&arr[0], 8, 0).unwrap();
+//! let h = histogram(&arr, &p);
+//! let mut sorted = vec![0; arr.len()];
+//! copy_by_histogram(&arr, &mut sorted, &h, &p);
+//! assert_eq!(sorted, vec![7, 0x0100_0001, 0x0100_0000, 0x0300_0000]);
+//! ```
+use std::fmt;
+
+use super::sorts::msd_sort;
+use super::sorts::ska_sort;
+use super::sorts::utils;
+use super::{RadixKey, Radixable};
+
+pub use super::algo::regions_graph::RegionsGraph;
+pub use super::sorts::utils::prefix_sums;
+
+const MAX_RADIX: usize = 16;
+// `get_full_histograms` is unrolled up to 16 levels.
+const MAX_UNROLLED_LEVELS: usize = 16;
+
+/// # Params error
+///
+/// Error returned when `Params` are not valid for a key.
+///
+/// - `Radix`: the radix must be between 1 and 16.
+/// - `Offset`: the offset must be lower than the number of bits of the key.
+/// - `Level`: the level must be lower than the number of levels.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParamsError {
+    Radix { radix: usize },
+    Offset { offset: usize, bits: usize },
+    Level { level: usize, max_level: usize },
+}
+
+impl fmt::Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamsError::Radix { radix } => write!(
+                f,
+                "[kernels] Invalid radix {}: the radix must be between 1 and \
+                 {}.",
+                radix, MAX_RADIX
+            ),
+            ParamsError::Offset { offset, bits } => write!(
+                f,
+                "[kernels] Invalid offset {}: the key has {} bits.",
+                offset, bits
+            ),
+            ParamsError::Level { level, max_level } => write!(
+                f,
+                "[kernels] Invalid level {}: there are {} levels.",
+                level, max_level
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParamsError {}
+
+/// # Params
+///
+/// The digit used by the kernels, checked against the number of bits of the
+/// key. The number of levels is computed from the radix and the offset.
+#[derive(Copy, Clone, Debug)]
+pub struct Params {
+    inner: utils::Params,
+    bits: usize,
+}
+
+impl Params {
+    /// Params of the first level, for the keys of the type of `dummy`.
+    pub fn new<T, K>(
+        dummy: &T,
+        radix: usize,
+        offset: usize,
+    ) -> Result<Params, ParamsError>
+    where
+        T: Radixable<K>,
+        K: RadixKey,
+    {
+        let bits = dummy.type_size();
+        if radix == 0 || radix > MAX_RADIX {
+            return Err(ParamsError::Radix { radix });
+        }
+        if offset >= bits {
+            return Err(ParamsError::Offset { offset, bits });
+        }
+
+        let max_level = utils::compute_max_level(bits, offset, radix);
+        let inner = utils::Params::new(0, radix, offset, max_level);
+
+        Ok(Params { inner, bits })
+    }
+    /// Same params, for another level.
+    pub fn with_level(&self, level: usize) -> Result<Params, ParamsError> {
+        if level >= self.inner.max_level {
+            return Err(ParamsError::Level {
+                level,
+                max_level: self.inner.max_level,
+            });
+        }
+
+        Ok(Params { inner: self.inner.new_level(level), bits: self.bits })
+    }
+    pub fn level(&self) -> usize { self.inner.level }
+    pub fn radix(&self) -> usize { self.inner.radix }
+    pub fn offset(&self) -> usize { self.inner.offset }
+    pub fn max_level(&self) -> usize { self.inner.max_level }
+    /// Number of buckets: `2^radix`.
+    pub fn radix_range(&self) -> usize { self.inner.radix_range }
+}
+
+fn check_bits<T, K>(dummy: &T, p: &Params)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert_eq!(
+        dummy.type_size(),
+        p.bits,
+        "[kernels] The params were built for keys of another size."
+    );
+}
+
+fn check_histogram(histogram: &[usize], size: usize, p: &Params) {
+    assert_eq!(
+        histogram.len(),
+        p.radix_range(),
+        "[kernels] The histogram must have radix_range buckets."
+    );
+    assert_eq!(
+        histogram.iter().sum::<usize>(),
+        size,
+        "[kernels] The histogram must count every item of the array."
+    );
+}
+
+/// Compute the offset of an array: the number of most significant bits which
+/// are zero in every key.
+///
+/// Returns the offset rounded down so that the remaining bits are a multiple
+/// of the radix (the offset of the LSD sorts), and the exact offset (the
+/// offset of the MSD sorts). Both are zero for an empty array.
+pub fn compute_offset<T, K>(arr: &[T], radix: usize) -> (usize, usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.is_empty() {
+        return (0, 0);
+    }
+    assert!(
+        radix > 0 && radix <= MAX_RADIX,
+        "[kernels] The radix must be between 1 and {}.",
+        MAX_RADIX
+    );
+
+    utils::compute_offset(arr, radix)
+}
+
+/// Digit of an item at the level of `p`, counted from the offset.
+pub fn digit<T, K>(item: &T, p: &Params) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    check_bits(item, p);
+    let (mask, shift) = item.get_mask_and_shift_from_left(&p.inner);
+    item.extract(mask, shift)
+}
+
+/// Histogram of the digits at the level of `p`.
+pub fn histogram<T, K>(arr: &[T], p: &Params) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.is_empty() {
+        return vec![0; p.radix_range()];
+    }
+
+    check_bits(&arr[0], p);
+    let (mask, shift) = arr[0].get_mask_and_shift_from_left(&p.inner);
+    utils::get_histogram(arr, &p.inner, mask, shift)
+}
+
+/// Histograms of every level, computed in one pass. The digits are counted
+/// from the least significant bit: `histograms[level]` counts the digits
+/// `(key >> (radix * (max_level - level - 1))) & (radix_range - 1)`.
+///
+/// The level of `p` is ignored.
+pub fn full_histograms<T, K>(arr: &[T], p: &Params) -> Vec<Vec<usize>>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.is_empty() {
+        return utils::get_empty_histograms(p.max_level(), p.radix_range());
+    }
+
+    let dummy = arr[0];
+    check_bits(&dummy, p);
+    if p.max_level() <= MAX_UNROLLED_LEVELS {
+        return utils::get_full_histograms(arr, &p.inner.new_level(0));
+    }
+
+    let mut histograms =
+        utils::get_empty_histograms(p.max_level(), p.radix_range());
+    for (level, histogram) in histograms.iter_mut().enumerate() {
+        let (mask, shift) = dummy.get_mask_and_shift(&p.inner.new_level(level));
+        for item in arr.iter() {
+            histogram[item.extract(mask, shift)] += 1;
+        }
+    }
+
+    histograms
+}
+
+/// Move the items of `arr` into their bucket, in place, and return the
+/// histogram of `arr` at the level of `p`. The order of the items of a bucket
+/// is not kept.
+///
+/// The histogram is computed here: the swaps are only valid with the exact
+/// histogram of the array.
+pub fn ska_swap<T, K>(arr: &mut [T], p: &Params) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let histogram = histogram(arr, p);
+    if arr.is_empty() {
+        return histogram;
+    }
+
+    let (mask, shift) = arr[0].get_mask_and_shift_from_left(&p.inner);
+    let (_, mut heads, tails) = prefix_sums(&histogram);
+    ska_sort::ska_swap(arr, &mut heads, &tails, mask, shift);
+
+    histogram
+}
+
+/// Copy the items of `source` into their bucket in `destination`. The order
+/// of the items of a bucket is kept.
+///
+/// `histogram` must be the histogram of `source` at the level of `p`. Panics
+/// if the arrays have different lengths, or if `histogram` does not count
+/// `source.len()` items in `radix_range` buckets.
+pub fn copy_by_histogram<T, K>(
+    source: &[T],
+    destination: &mut [T],
+    histogram: &[usize],
+    p: &Params,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert_eq!(
+        source.len(),
+        destination.len(),
+        "[kernels] The source and the destination must have the same length."
+    );
+    check_histogram(histogram, source.len(), p);
+    if source.is_empty() {
+        return;
+    }
+
+    check_bits(&source[0], p);
+    let (mask, shift) = source[0].get_mask_and_shift_from_left(&p.inner);
+    let (_, mut heads, _) = prefix_sums(histogram);
+    msd_sort::copy_by_histogram(
+        source.len(),
+        source,
+        destination,
+        &mut heads,
+        mask,
+        shift,
+    );
+}
@@ -37,11 +37,29 @@
 //! [`PartialOrd`](https://doc.rust-lang.org/std/cmp/trait.PartialOrd.html),
 //! [`PartialEq`](https://doc.rust-lang.org/std/cmp/trait.PartialEq.html)
 //! and [`Copy`](https://doc.rust-lang.org/std/marker/trait.Copy.html) (and thus, Clone trait too)
-//! traits and `Radixable` trait (see below) (Mapped to a key).
+//! traits and `Radixable` trait (see below) (Mapped to a key),
+//! - [`Option`](https://doc.rust-lang.org/std/option/enum.Option.html) of
+//!   one of the types above, except 128 bits types (An extra leading bit is
+//!   added to the key). `None` values come first, like with the Rust
+//!   standard sort. Wrap the values into a `NoneLast` to put `None` values
+//!   last. An `Option` or a `NoneLast` can also be the key of a custom struct.
+//! - [`Duration`](https://doc.rust-lang.org/std/time/struct.Duration.html)
+//!   (96 bits key: seconds then nanoseconds),
+//!   [`SystemTime`](https://doc.rust-lang.org/std/time/struct.SystemTime.html),
+//!   [`Ipv4Addr`](https://doc.rust-lang.org/std/net/struct.Ipv4Addr.html),
+//!   [`Ipv6Addr`](https://doc.rust-lang.org/std/net/struct.Ipv6Addr.html),
+//!   the `NonZero` integers,
+//!   [`Wrapping`](https://doc.rust-lang.org/std/num/struct.Wrapping.html)
+//!   and [`Reverse`](https://doc.rust-lang.org/std/cmp/struct.Reverse.html)
+//!   (The key bits are inverted).
+//! - `F16` and `BF16`, the raw `u16` bit patterns of half precision floats
+//!   (Same sign flip as `f32`).
 //!
 //! Vocarious sort can only sort in ascending order. You can call the
 //! [`reverse`](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.reverse)
-//! method if desired.
+//! method if desired, or use a
+//! [`Reverse`](https://doc.rust-lang.org/std/cmp/struct.Reverse.html) key to
+//! sort a field in descending order.
 //!
 //! Because of Rust Orphan Rule, we chose not to support tuple sorting. You
 //! can use [struct](https://doc.rust-lang.org/std/keyword.struct.html) instead.
@@ -77,9 +95,93 @@
 //!
 //! When the Crate is imported, three methods are added to vectors and slices:
 //! - `voracious_sort()` (single thread).
-//! - `voracious_stable_sort()` (single thread).
+//! - `voracious_stable_sort()` (single thread, items with equal keys keep their
+//!   relative order, for every type and every size).
 //! - `voracious_mt_sort()` (multi thread).
 //!
+//! When `key()` does real work (decoding, hashing, ...), the radix sorts call
+//! it once per item and per pass. `voracious_sort_by_cached_key()` extracts
+//! the keys once into (key, index) pairs, sorts them, and moves the items.
+//! A type can opt in by returning `true` from `Radixable::cache_key`, then
+//! the three methods above use the cached keys automatically. They also do
+//! for items of 64 bytes or more, which are then moved once instead of once
+//! per pass. The items are moved in place with `apply_permutation_in_place`.
+//! Small arrays are still sorted directly, by the dispatchers.
+//!
+//! When the keys lie in a narrow range far from zero, such as timestamps,
+//! `range_sort` (and `range_mt_sort`) subtracts the smallest key and only
+//! sorts the digits of the range. If the bounds are known,
+//! `voracious_sort_with_range(min, max)` skips the scan for them.
+//!
+//! Types which can not implement `Radixable`, such as records holding a
+//! `String` or a `Vec`, can be sorted by a radix key with the
+//! `RadixSortByKey` trait: `voracious_sort_by_key(|x| x.id)`, and its stable
+//! and multithread variants. When the order is only partly radixable,
+//! `voracious_sort_by_key_then(|x| x.ts, |a, b| a.name.cmp(&b.name))` sorts
+//! by the radix key, then only the runs of equal keys by the comparator.
+//!
+//! Iterators can also be collected into a sorted vector, the histograms are
+//! computed while the items are collected:
+//! - `voracious_sorted()` on an `Iterator` (single thread, stable).
+//! - `par_collect_voracious_sorted(thread_n)` on a Rayon `ParallelIterator`
+//!   (multi thread).
+//!
+//! Data received in batches can be sorted with a `StreamingSorter`: each
+//! batch is sorted when it is pushed, and the sorted runs are merged by tiers.
+//!
+//! A `RadixSortedVec` keeps its elements sorted: the batches are sorted and
+//! merged in bulk, and it can be queried by range of values.
+//!
+//! Two slices can be joined on a radix key with `radix_join` (inner join),
+//! `radix_left_join`, `radix_semi_join` and `radix_anti_join`, which return
+//! indices. Both sides are partitioned on the same digits, then each pair of
+//! buckets is sorted and merge joined. The `_mt` variants join the buckets in
+//! parallel.
+//!
+//! `radix_partition(arr, bits_from, radix)` scatters an array on a single
+//! digit of the key, without sorting it, and returns the boundaries of the
+//! buckets. `radix_partition_stable` and `radix_partition_mt` are the stable
+//! and multithread variants.
+//!
+//! `voracious_sort_by_bits(arr, 0..20)` sorts by a range of bits of the key
+//! only, counted from the most significant bit, such as the top bits of a
+//! Morton code. The order within the items whose bits are equal is
+//! unspecified, `voracious_stable_sort_by_bits` keeps it.
+//!
+//! When the exact order is not needed below a precision, such as for plots
+//! or approximate quantiles, `voracious_sort_approx(arr, precision_bits)`
+//! only sorts the most significant bits of the keys: every item ends up in
+//! its bucket of equal bits. `voracious_sort_approx_cleanup` then sorts each
+//! bucket to get the exact order.
+//!
+//! Any `Copy` type can be partitioned without a radix key:
+//! `voracious_partition_stable(arr, |x| pred)` moves the items which satisfy
+//! a predicate first, and `voracious_partition_by(arr, n, |x| bucket)`
+//! scatters the items into `n` buckets and returns their boundaries. Both
+//! are stable, with an unstable inplace variant and a multithread variant.
+//!
+//! For group by aggregations, `voracious_cluster(arr)` only puts the equal
+//! keys next to each other, in groups of an arbitrary order, and returns the
+//! boundaries of the groups. The keys are hashed once, and the items are
+//! sorted by hash instead of by key. `voracious_cluster_mt` is the
+//! multithread variant.
+//!
+//! Tables stored as separate columns can be sorted with `sort_columns`, like
+//! the SQL `ORDER BY a, b DESC, c`: each key column has its own direction,
+//! and every column, key or `Payload`, is permuted with the rows.
+//!
+//! The `RadixSortChecked` trait adds `voracious_sort_checked()`,
+//! `voracious_stable_sort_checked()` and `voracious_mt_sort_checked()`. They
+//! verify that the output is sorted, and that the `Radixable` key agrees with
+//! `PartialOrd` on a sample of pairs. The first offending pair is returned in
+//! a `CheckError`. With the `checked` feature, the `RadixSort` methods panic
+//! on such an error.
+//!
+//! The `testing` module helps to check a custom `Radixable` implementation:
+//! `testing::check_radixable(&samples)` verifies that the methods of the trait
+//! agree with each other and with `PartialOrd`, and runs every raw sort
+//! against a reference sort.
+//!
 //! ### Example
 //!
 //! ```
@@ -226,6 +328,43 @@
 //! ]);
 //! ```
 //!
+//! ### Deriving `Radixable`
+//!
+//! With the `derive` feature, the `PartialOrd`, `PartialEq` and `Radixable`
+//! implementations can be derived. The key fields are marked with
+//! `#[radix(key)]` (or `#[radix(desc)]` for a descending field). Several key
+//! fields form a lexicographic `CompositeKey`, in the declaration order.
+//! Fieldless enums are sorted by discriminant.
+//!
+//! Without the `derive` feature, `key()` can return a `KeySpec`: a builder
+//! which concatenates several fields, each ascending or descending, with the
+//! `None` values of `Option` fields first or last. The descending fields have
+//! their key bits inverted, so the usual sorts apply unchanged.
+//!
+//! ```ignore
+//! fn key(&self) -> KeySpec {
+//!     KeySpec::new().asc(self.region).desc(self.score)
+//! }
+//! ```
+//!
+//! ```toml
+//! [dependencies]
+//! voracious_radix_sort = { version = "1.0.0", features = ["derive"] }
+//! ```
+//!
+//! ```ignore
+//! use voracious_radix_sort::{RadixSort, Radixable};
+//!
+//! #[derive(Copy, Clone, Debug, Radixable)]
+//! pub struct Custom {
+//!     #[radix(key)]
+//!     day: u16,
+//!     #[radix(desc)]
+//!     value: f32,
+//!     other: usize,
+//! }
+//! ```
+//!
 //! ### Panics
 //!
 //! For [`f32`](https://doc.rust-lang.org/stable/std/primitive.f32.html) and
@@ -242,6 +381,7 @@
 //! ## Dependencies
 //!
 //! - Rayon 1.4.0 (threadpool).
+//! - Syn 1.0, Quote 1.0 and Proc-macro2 1.0 (Only with the `derive` feature).
 //!
 //! ## Performances
 //!
@@ -340,10 +480,18 @@
 //! That way you can do whatever you want as long as you know what you are doing.
 //!
 //! Using another value than 8 for the radix is your responsibility.
+//! Alternatively, `sort_with(arr, Algorithm::Msd, params)` runs a raw sort
+//! after checking its params: each `Algorithm` tells whether it is stable,
+//! in place or multithread, and which radix values it supports for a key. An
+//! invalid param is returned as a `SortError`.
 //!
 //! **I can ensure you that sorting with the trait methods is correct (erk I hope ^_^). But if you play
 //! with native functions, it is up to you not to do mischief.**
 //!
+//! To build your own radix sort, the `kernels` module exposes the building
+//! blocks of the sorts (histograms, prefix sums, swaps and copies by
+//! histogram, offsets and the regions graph) with validated parameters.
+//!
 //! Since [profiling](https://github.com/lakwet/voracious_sort/blob/master/PROFILING.md)
 //! is not finished. You might need to work a bit more by doing your own profiling.
 //! For this purpose, I highly recommend you to clone the github project and use
@@ -352,18 +500,47 @@
 mod algo;
 mod dedicated;
 #[cfg(test)] mod generators;
+pub mod kernels;
 mod sorts;
 #[cfg(test)] mod tests;
+pub mod testing;
 mod traits;
 mod types;
 
+pub use algo::checked::CheckError;
+pub use algo::columns::{sort_columns, Column, Payload, SortKeySpec};
+pub use algo::permutation::apply_permutation_in_place;
+pub use algo::radix_join::{
+    radix_anti_join, radix_anti_join_mt, radix_join, radix_join_mt,
+    radix_left_join, radix_left_join_mt, radix_semi_join, radix_semi_join_mt,
+};
+pub use algo::radix_sorted_vec::RadixSortedVec;
+pub use algo::registry::{sort_with, Algorithm, SortError, SortParams};
+pub use algo::streaming_sorter::StreamingSorter;
+pub use traits::checked_sort::RadixSortChecked;
+pub use traits::collect_sorted::{ParVoraciousSorted, VoraciousSorted};
 pub use traits::dispatcher::Dispatcher;
 pub use traits::radix_key::RadixKey;
 pub use traits::radixable::Radixable;
 pub use traits::radixsort::RadixSort;
+pub use traits::sort_by_key::RadixSortByKey;
+#[cfg(feature = "derive")]
+pub use voracious_radix_sort_derive::Radixable;
+pub use types::composite::CompositeKey;
+pub use types::half::{BF16, F16};
+pub use types::key_spec::{KeySpec, Nulls};
+pub use types::option::NoneLast;
 
 pub use sorts::american_flag_sort::american_flag_sort;
+pub use sorts::approx_sort::{
+    voracious_sort_approx, voracious_sort_approx_cleanup,
+};
 pub use sorts::boolean_sort::boolean_sort;
+pub use sorts::cached_key_sort::{
+    cached_key_mt_sort, cached_key_sort, cached_key_stable_sort,
+};
+pub use sorts::cluster::{voracious_cluster, voracious_cluster_mt};
+pub use sorts::collect_sort::{collect_sorted, par_collect_sorted};
 pub use sorts::comparative_sort::insertion_sort;
 pub use sorts::counting_sort::counting_sort;
 pub use sorts::dlsd_sort::dlsd_radixsort;
@@ -371,8 +548,20 @@ pub use sorts::lsd_sort::lsd_radixsort;
 pub use sorts::lsd_stable_sort::lsd_stable_radixsort;
 pub use sorts::msd_sort::msd_radixsort;
 pub use sorts::msd_stable_sort::msd_stable_radixsort;
+pub use sorts::partition::{
+    voracious_partition_by, voracious_partition_by_mt,
+    voracious_partition_by_unstable, voracious_partition_stable,
+    voracious_partition_stable_mt, voracious_partition_unstable,
+};
+pub use sorts::radix_partition::{
+    radix_partition, radix_partition_mt, radix_partition_stable,
+};
+pub use sorts::range_sort::{range_mt_sort, range_sort};
 pub use sorts::rollercoaster_sort::rollercoaster_sort;
 pub use sorts::ska_sort::ska_sort;
+pub use sorts::sort_by_bits::{
+    voracious_sort_by_bits, voracious_stable_sort_by_bits,
+};
 pub use sorts::thiel_sort::thiel_radixsort;
 pub use sorts::voracious_sort::voracious_sort;
 
@@ -0,0 +1,89 @@
+use super::super::{RadixKey, RadixSort, Radixable};
+use super::comparative_sort::insertion_sort;
+use super::sort_by_bits::{bit_field, voracious_sort_by_bits};
+use super::utils::compute_offset;
+
+const INSERTION_SIZE: usize = 32;
+
+// The significant bits start after the leading zero bits of every key.
+fn approx_bits<T, K>(arr: &[T], precision_bits: usize) -> (usize, usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let (_, raw_offset) = compute_offset(arr, 8);
+    let bits_to = (raw_offset + precision_bits).min(arr[0].type_size());
+    (raw_offset, bits_to)
+}
+
+/// # Voracious sort approx
+///
+/// Sort the array by the `precision_bits` most significant bits of the keys
+/// only, after the leading zero bits shared by every key. The items whose
+/// significant bits are equal form a bucket, in an unspecified order: every
+/// item is within its bucket of its position in the sorted array.
+///
+/// It is a Voracious sort by bits (see `voracious_sort_by_bits`) on the
+/// significant bits, with fewer passes than a full sort. With
+/// `precision_bits` above the number of bits of the key, the order is exact.
+///
+/// ```
+/// use voracious_radix_sort::voracious_sort_approx;
+///
+/// let mut arr: Vec<f32> = vec![3.5, -4.0, 3.0, 100.0, -1.5, 0.0];
+/// voracious_sort_approx(&mut arr, 8);
+/// // The sign and the exponent are sorted, the mantissa is not.
+/// assert_eq!(&arr[..3], &[-4.0, -1.5, 0.0]);
+/// assert!(arr[3..5] == [3.0, 3.5] || arr[3..5] == [3.5, 3.0]);
+/// assert_eq!(arr[5], 100.0);
+/// ```
+pub fn voracious_sort_approx<T, K>(arr: &mut [T], precision_bits: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() < 2 {
+        return;
+    }
+
+    let (bits_from, bits_to) = approx_bits(arr, precision_bits);
+    voracious_sort_by_bits(arr, bits_from..bits_to);
+}
+
+/// # Voracious sort approx cleanup
+///
+/// Same as the Voracious sort approx, then each bucket is sorted: the small
+/// buckets with an insertion sort, the others with the Voracious sort. Thus
+/// the order is exact.
+///
+/// It is faster than a full sort when most buckets hold a few items.
+pub fn voracious_sort_approx_cleanup<T, K>(arr: &mut [T], precision_bits: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() < 2 {
+        return;
+    }
+
+    let (bits_from, bits_to) = approx_bits(arr, precision_bits);
+    voracious_sort_by_bits(arr, bits_from..bits_to);
+
+    let field = |item: &T| bit_field(item, bits_from, bits_to);
+    let mut start = 0;
+    while start < arr.len() {
+        let current = field(&arr[start]);
+        let mut end = start + 1;
+        while end < arr.len() && field(&arr[end]) == current {
+            end += 1;
+        }
+
+        let bucket = &mut arr[start..end];
+        if bucket.len() <= INSERTION_SIZE {
+            insertion_sort(bucket);
+        } else {
+            bucket.voracious_sort();
+        }
+        start = end;
+    }
+}
@@ -0,0 +1,363 @@
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+use super::super::algo::permutation::{apply_permutation, PermIndex};
+use super::super::traits::radix_key::key_to_u128;
+use super::super::{RadixKey, RadixSort, Radixable};
+use super::msd_sort::copy_by_histogram;
+use super::utils::{compute_offset, get_histogram, prefix_sums, Params};
+
+// Items of this size or more are sorted through (key, index) pairs, since the
+// radix sorts would copy them on every pass.
+pub(crate) const INDIRECT_SIZE: usize = 64;
+
+// Below this length, the dispatchers sort with a comparison sort, which does
+// not need the cached keys.
+pub(crate) const CACHED_KEYS_MIN_LEN: usize = 512;
+
+// A key extracted once, with the position of its item in the array. The
+// index is a u32, unless the array is too long for it.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct CachedKey<U, I = u32> {
+    pub key: U,
+    pub index: I,
+}
+
+impl<U: PartialOrd, I> PartialOrd for CachedKey<U, I> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.key.partial_cmp(&other.key)
+    }
+}
+
+impl<U: PartialEq, I> PartialEq for CachedKey<U, I> {
+    fn eq(&self, other: &Self) -> bool { self.key == other.key }
+}
+
+impl<I: PermIndex> Radixable<u32> for CachedKey<u32, I> {
+    type Key = u32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+
+impl<I: PermIndex> Radixable<u64> for CachedKey<u64, I> {
+    type Key = u64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+
+impl<I: PermIndex> Radixable<u128> for CachedKey<u128, I> {
+    type Key = u128;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+
+// A sort of cached keys, generic over the key type `U`, in which the keys are
+// narrowed from u128, and over the index type `I`.
+pub(crate) trait CachedKeyTask {
+    type Output;
+
+    fn run<U, I>(self, narrow: fn(u128) -> U) -> Self::Output
+    where
+        CachedKey<U, I>: Radixable<U>,
+        U: RadixKey,
+        I: PermIndex;
+}
+
+// Runs `task` with the smallest key type which holds `bits` bits, and with
+// u32 indices if `len` items can be indexed with them.
+pub(crate) fn run_cached_key_task<Task: CachedKeyTask>(
+    bits: usize,
+    len: usize,
+    task: Task,
+) -> Task::Output {
+    if len <= u32::MAX as usize {
+        run_cached_key_task_with::<Task, u32>(bits, task)
+    } else {
+        run_cached_key_task_with::<Task, usize>(bits, task)
+    }
+}
+
+fn run_cached_key_task_with<Task: CachedKeyTask, I: PermIndex>(
+    bits: usize,
+    task: Task,
+) -> Task::Output {
+    if bits <= 32 {
+        task.run::<u32, I>(|k| k as u32)
+    } else if bits <= 64 {
+        task.run::<u64, I>(|k| k as u64)
+    } else {
+        task.run::<u128, I>(|k| k)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub(crate) enum Mode {
+    Unstable,
+    Stable,
+    Multithread(usize),
+}
+
+pub(crate) fn sort_cached_keys<U, I>(keys: &mut [CachedKey<U, I>], mode: Mode)
+where
+    CachedKey<U, I>: Radixable<U>,
+    U: RadixKey,
+{
+    match mode {
+        Mode::Unstable => keys.voracious_sort(),
+        Mode::Stable => keys.voracious_stable_sort(),
+        Mode::Multithread(thread_n) => keys.voracious_mt_sort(thread_n),
+    }
+}
+
+// Items compared by `then`, when their keys are equal.
+type TieBreak<'a, T> = Option<&'a dyn Fn(&T, &T) -> Ordering>;
+
+const RADIX: usize = 8;
+const SMALL_SIZE: usize = 128;
+
+// Stable MSD radix sort of the pairs, like `msd_radixsort_rec`. The buckets
+// of the last digit are the runs of equal keys: they are sorted by
+// `compare`. The small buckets are sorted by key, then by `compare`.
+fn sort_cached_keys_then_rec<T, U, I>(
+    arr: &[T],
+    keys: &mut [CachedKey<U, I>],
+    p: Params,
+    compare: &dyn Fn(&T, &T) -> Ordering,
+) where
+    CachedKey<U, I>: Radixable<U>,
+    U: RadixKey,
+    I: PermIndex,
+{
+    let compare_items = |a: &CachedKey<U, I>, b: &CachedKey<U, I>| {
+        compare(&arr[a.index.to_usize()], &arr[b.index.to_usize()])
+    };
+    if keys.len() <= SMALL_SIZE {
+        keys.sort_by(|a, b| {
+            a.partial_cmp(b).unwrap().then_with(|| compare_items(a, b))
+        });
+        return;
+    }
+
+    let dummy = keys[0];
+    let (mask, shift) = dummy.get_mask_and_shift_from_left(&p);
+    let histogram = get_histogram(keys, &p, mask, shift);
+    let (p_sums, mut heads, _) = prefix_sums(&histogram);
+
+    let buffer = keys.to_vec();
+    copy_by_histogram(keys.len(), &buffer, keys, &mut heads, mask, shift);
+    drop(buffer);
+
+    let mut rest = keys;
+    for i in 0..p.radix_range {
+        let (bucket, next) = rest.split_at_mut(p_sums[i + 1] - p_sums[i]);
+        rest = next;
+        if bucket.len() < 2 {
+            continue;
+        }
+        if p.level < p.max_level - 1 {
+            let new_params = p.new_level(p.level + 1);
+            sort_cached_keys_then_rec(arr, bucket, new_params, compare);
+        } else {
+            bucket.sort_by(compare_items);
+        }
+    }
+}
+
+fn sort_cached_keys_then<T, U, I>(
+    arr: &[T],
+    keys: &mut [CachedKey<U, I>],
+    compare: &dyn Fn(&T, &T) -> Ordering,
+) where
+    CachedKey<U, I>: Radixable<U>,
+    U: RadixKey,
+    I: PermIndex,
+{
+    let dummy = keys[0];
+    let (_, raw_offset) = compute_offset(keys, RADIX);
+    let max_level = dummy.compute_max_level(raw_offset, RADIX);
+    if max_level == 0 {
+        // Every key is equal.
+        keys.sort_by(|a, b| {
+            compare(&arr[a.index.to_usize()], &arr[b.index.to_usize()])
+        });
+        return;
+    }
+
+    let params = Params::new(0, RADIX, raw_offset, max_level);
+    sort_cached_keys_then_rec(arr, keys, params, compare);
+}
+
+// Sorts the items by their extracted key, through the cached keys.
+struct SortByExtractedKey<'a, T, R, F> {
+    arr: &'a mut [T],
+    extract: &'a F,
+    then: TieBreak<'a, T>,
+    mode: Mode,
+    key: PhantomData<fn() -> R>,
+}
+
+impl<'a, T, R, F> CachedKeyTask for SortByExtractedKey<'a, T, R, F>
+where
+    F: Fn(&T) -> R,
+    R: RadixKey,
+{
+    type Output = ();
+
+    fn run<U, I>(self, narrow: fn(u128) -> U)
+    where
+        CachedKey<U, I>: Radixable<U>,
+        U: RadixKey,
+        I: PermIndex,
+    {
+        let extract = self.extract;
+        let mut keys: Vec<CachedKey<U, I>> = self
+            .arr
+            .iter()
+            .enumerate()
+            .map(|(index, item)| CachedKey {
+                key: narrow(key_to_u128(&extract(item))),
+                index: I::from_usize(index),
+            })
+            .collect();
+
+        // The items are still at their original position, the comparator
+        // reads them through the indices.
+        match self.then {
+            Some(compare) => {
+                sort_cached_keys_then(self.arr, &mut keys, compare)
+            },
+            None => sort_cached_keys(&mut keys, self.mode),
+        }
+
+        let mut perm: Vec<I> = keys.iter().map(|cached| cached.index).collect();
+        drop(keys);
+        apply_permutation(self.arr, &mut perm);
+    }
+}
+
+fn sort_by_extracted_key_dispatch<T, R, F>(
+    arr: &mut [T],
+    extract: F,
+    then: TieBreak<T>,
+    mode: Mode,
+) where
+    F: Fn(&T) -> R,
+    R: RadixKey,
+{
+    if arr.len() < 2 {
+        return;
+    }
+
+    // The key is not extracted here, only its type is needed.
+    let bits = size_of::<R::Key>() * 8;
+    let len = arr.len();
+    let task = SortByExtractedKey {
+        arr,
+        extract: &extract,
+        then,
+        mode,
+        key: PhantomData,
+    };
+    run_cached_key_task(bits, len, task);
+}
+
+// The items are only moved by swaps, once all the keys are sorted: if
+// `extract` panics, the array is left untouched. Thus the items do not need
+// to be Copy.
+pub(crate) fn sort_by_extracted_key<T, R, F>(
+    arr: &mut [T],
+    extract: F,
+    mode: Mode,
+) where
+    F: Fn(&T) -> R,
+    R: RadixKey,
+{
+    sort_by_extracted_key_dispatch(arr, extract, None, mode);
+}
+
+// The pairs are sorted by a stable MSD radix sort, and the buckets of the
+// last digit, which hold equal keys, are sorted by `compare`, with a stable
+// sort too.
+pub(crate) fn sort_by_extracted_key_then<T, R, F, C>(
+    arr: &mut [T],
+    extract: F,
+    compare: C,
+) where
+    F: Fn(&T) -> R,
+    R: RadixKey,
+    C: Fn(&T, &T) -> Ordering,
+{
+    sort_by_extracted_key_dispatch(arr, extract, Some(&compare), Mode::Stable);
+}
+
+// The Radixable sorts go through the cached keys if the key is expensive or
+// if the items are large, unless the array is small.
+#[inline]
+pub(crate) fn use_cached_keys<T: Radixable<K>, K: RadixKey>(
+    dummy: &T,
+    len: usize,
+) -> bool {
+    len >= CACHED_KEYS_MIN_LEN
+        && (dummy.cache_key() || size_of::<T>() >= INDIRECT_SIZE)
+}
+
+pub(crate) fn cached_key_dispatch<T, K>(arr: &mut [T], mode: Mode)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    sort_by_extracted_key(arr, |item: &T| item.key(), mode);
+}
+
+/// # Cached key sort
+///
+/// Sort an array whose `key()` method is expensive.
+///
+/// The keys are extracted once into a compact array of (key, index) pairs,
+/// which is sorted by the Voracious sort. The items are then moved in place to
+/// their final position, with `apply_permutation_in_place`. Thus `key()` is
+/// called exactly once per item, whereas the radix sorts call it once per item
+/// and per pass. It is also faster for large items, which are moved once
+/// instead of once per pass.
+///
+/// The indices are stored on 32 bits, unless the array is longer than
+/// `u32::MAX`.
+///
+/// The Cached key sort is an unstable radix sort. It allocates the pairs, but
+/// not a copy of the array.
+pub fn cached_key_sort<T, K>(arr: &mut [T])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    cached_key_dispatch(arr, Mode::Unstable);
+}
+
+/// # Cached key stable sort
+///
+/// Same as the Cached key sort, but the (key, index) pairs are sorted with a
+/// stable sort.
+///
+/// The Cached key stable sort is a stable radix sort.
+pub fn cached_key_stable_sort<T, K>(arr: &mut [T])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    cached_key_dispatch(arr, Mode::Stable);
+}
+
+/// # Cached key multithread sort
+///
+/// Same as the Cached key sort, but the (key, index) pairs are sorted with
+/// the multithread Voracious sort.
+///
+/// The Cached key multithread sort is an unstable radix sort.
+pub fn cached_key_mt_sort<T, K>(arr: &mut [T], thread_n: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    cached_key_dispatch(arr, Mode::Multithread(thread_n));
+}
@@ -0,0 +1,273 @@
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use super::super::algo::permutation::{apply_permutation, PermIndex};
+use super::super::traits::radix_key::key_to_u128;
+use super::super::{RadixKey, RadixSort, Radixable};
+use super::cached_key_sort::{CachedKey, Mode};
+use super::partition::{
+    voracious_partition_by_mt, voracious_partition_by_unstable,
+};
+
+const RADIX: usize = 8;
+const RADIX_RANGE: usize = 1 << RADIX;
+const SMALL_SIZE: usize = 64;
+const PARALLEL_THRESHOLD: usize = 20_000;
+
+// Finalizer of the Murmur3 hash: every bit of the key changes every digit.
+#[inline]
+pub(crate) fn fmix64(mut h: u64) -> u64 {
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xC4CE_B9FE_1A85_EC53);
+    h ^= h >> 33;
+    h
+}
+
+#[inline]
+fn hash<T, K>(item: &T) -> u64
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let key = key_to_u128(&item.key());
+    fmix64(key as u64 ^ fmix64((key >> 64) as u64))
+}
+
+fn is_one_group<T, K>(arr: &[T]) -> bool
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let first = arr[0].into_key_type();
+    arr.iter().all(|item| item.into_key_type() == first)
+}
+
+fn group_boundaries<T, K>(arr: &[T]) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let mut boundaries = vec![0];
+    for (i, pair) in arr.windows(2).enumerate() {
+        if pair[0].into_key_type() != pair[1].into_key_type() {
+            boundaries.push(i + 1);
+        }
+    }
+    if !arr.is_empty() {
+        boundaries.push(arr.len());
+    }
+
+    boundaries
+}
+
+// Small arrays are sorted by key.
+fn cluster_by_key<T, K>(arr: &mut [T]) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= SMALL_SIZE {
+        arr.sort_unstable_by_key(|item| item.into_key_type());
+    } else {
+        arr.voracious_sort();
+    }
+
+    group_boundaries(arr)
+}
+
+#[inline]
+fn hash_digit<I>(pair: &CachedKey<u64, I>, shift: usize) -> usize {
+    ((pair.key >> shift) as usize) & (RADIX_RANGE - 1)
+}
+
+// The pairs are scattered on the digits of the hashes, from the digit at
+// `shift` down, with the swaps of the Ska sort. A bucket stops as soon as its
+// hashes are all equal: the equal hashes only need to be next to each other.
+fn scatter_hashes_rec<I: PermIndex>(
+    pairs: &mut [CachedKey<u64, I>],
+    shift: usize,
+) {
+    if pairs.len() <= SMALL_SIZE {
+        pairs.sort_unstable_by_key(|pair| pair.key);
+        return;
+    }
+    let first = pairs[0].key;
+    if pairs.iter().all(|pair| pair.key == first) {
+        return;
+    }
+
+    let p_sums = voracious_partition_by_unstable(pairs, RADIX_RANGE, |pair| {
+        hash_digit(pair, shift)
+    });
+    if shift == 0 {
+        return;
+    }
+
+    let mut rest = pairs;
+    for bounds in p_sums.windows(2) {
+        let (bucket, next) = rest.split_at_mut(bounds[1] - bounds[0]);
+        rest = next;
+        if bucket.len() > 1 {
+            scatter_hashes_rec(bucket, shift - RADIX);
+        }
+    }
+}
+
+// The first digit is partitioned with several threads, then the buckets are
+// scattered in parallel.
+fn scatter_hashes_mt<I: PermIndex>(
+    pairs: &mut [CachedKey<u64, I>],
+    thread_n: usize,
+) {
+    let shift = 64 - RADIX;
+    let p_sums = voracious_partition_by_mt(
+        pairs,
+        RADIX_RANGE,
+        |pair| hash_digit(pair, shift),
+        thread_n,
+    );
+
+    let mut buckets = Vec::with_capacity(RADIX_RANGE);
+    let mut rest = pairs;
+    for bounds in p_sums.windows(2) {
+        let (bucket, next) = rest.split_at_mut(bounds[1] - bounds[0]);
+        rest = next;
+        if bucket.len() > 1 {
+            buckets.push(bucket);
+        }
+    }
+
+    let pool = ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+    pool.install(|| {
+        buckets
+            .into_par_iter()
+            .for_each(|bucket| scatter_hashes_rec(bucket, shift - RADIX))
+    });
+}
+
+// The keys are hashed into (hash, index) pairs, which are scattered until the
+// equal hashes are next to each other, then the items are moved in place
+// once. Each run of equal hashes is a group, unless several keys collide: the
+// run is then sorted by key.
+fn cluster_by_hash<T, K, I>(arr: &mut [T], mode: Mode) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+    I: PermIndex,
+{
+    let pair = |(index, item): (usize, &T)| CachedKey {
+        key: hash(item),
+        index: I::from_usize(index),
+    };
+    let hashes: Vec<CachedKey<u64, I>> = match mode {
+        Mode::Multithread(thread_n) => {
+            let pool =
+                ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+            let mut hashes: Vec<_> =
+                pool.install(|| arr.par_iter().enumerate().map(pair).collect());
+            scatter_hashes_mt(&mut hashes, thread_n);
+            hashes
+        },
+        _ => {
+            let mut hashes: Vec<_> = arr.iter().enumerate().map(pair).collect();
+            scatter_hashes_rec(&mut hashes, 64 - RADIX);
+            hashes
+        },
+    };
+
+    let mut perm: Vec<I> = hashes.iter().map(|cached| cached.index).collect();
+    apply_permutation(arr, &mut perm);
+    drop(perm);
+
+    let mut boundaries = vec![0];
+    let mut start = 0;
+    while start < arr.len() {
+        let hash = hashes[start].key;
+        let run_len =
+            hashes[start..].iter().take_while(|c| c.key == hash).count();
+        let end = start + run_len;
+        let run = &mut arr[start..end];
+        if is_one_group(run) {
+            boundaries.push(end);
+        } else {
+            run.sort_unstable_by_key(|item| item.into_key_type());
+            let groups = group_boundaries(run);
+            boundaries.extend(groups[1..].iter().map(|b| start + b));
+        }
+        start = end;
+    }
+
+    boundaries
+}
+
+// The indices are stored on 32 bits, unless the array is too long for them.
+fn cluster_dispatch<T, K>(arr: &mut [T], mode: Mode) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= u32::MAX as usize {
+        cluster_by_hash::<T, K, u32>(arr, mode)
+    } else {
+        cluster_by_hash::<T, K, usize>(arr, mode)
+    }
+}
+
+/// # Voracious cluster
+///
+/// Move the items with equal keys next to each other, without sorting the
+/// groups, and return the boundaries of the groups: the items of group `i`
+/// are `arr[b[i]..b[i + 1]]`.
+///
+/// The keys are hashed once into (hash, index) pairs, which are scattered on
+/// the digits of the hashes, with the swaps of the Ska sort, until each
+/// bucket holds a single hash. The items are then moved in place once, so
+/// the equal keys end up next to each other. The rare runs
+/// of equal hashes which hold several keys are sorted by key. Thus the groups
+/// come in an arbitrary order, and the clustering is unstable.
+///
+/// It is meant for group by aggregations, on keys with many distinct values.
+///
+/// ```
+/// use voracious_radix_sort::voracious_cluster;
+///
+/// let mut arr: Vec<u64> = vec![7, 3, 7, 1, 3, 7];
+/// let groups = voracious_cluster(&mut arr);
+/// assert_eq!(groups.len(), 4);
+/// for group in groups.windows(2) {
+///     let items = &arr[group[0]..group[1]];
+///     assert!(items.iter().all(|x| *x == items[0]));
+/// }
+/// ```
+pub fn voracious_cluster<T, K>(arr: &mut [T]) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= SMALL_SIZE {
+        return cluster_by_key(arr);
+    }
+
+    cluster_dispatch(arr, Mode::Unstable)
+}
+
+/// # Voracious cluster multithread
+///
+/// Same as the Voracious cluster, with several threads: the keys are hashed
+/// in parallel, the first digit of the hashes is partitioned with several
+/// threads, then the buckets are scattered in parallel.
+///
+/// For small arrays, it fallbacks on the single thread Voracious cluster.
+pub fn voracious_cluster_mt<T, K>(arr: &mut [T], thread_n: usize) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= PARALLEL_THRESHOLD {
+        return voracious_cluster(arr);
+    }
+
+    cluster_dispatch(arr, Mode::Multithread(thread_n))
+}
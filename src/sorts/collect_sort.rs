@@ -0,0 +1,197 @@
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use std::iter::once;
+
+use super::super::{RadixKey, RadixSort, Radixable};
+use super::lsd_sort::lsd_radixsort_with_histograms;
+use super::utils::{get_empty_histograms, only_one_bucket_filled, Params};
+
+const RADIX: usize = 8;
+const FALLBACK_THRESHOLD: usize = 128;
+
+// The digits are aligned on the least significant bit, like in the LSD sort,
+// so that the histograms do not depend on the biggest key.
+fn full_params<T: Radixable<K>, K: RadixKey>(dummy: &T) -> Params {
+    let max_level = dummy.compute_max_level(0, RADIX);
+    Params::new(0, RADIX, 0, max_level)
+}
+
+#[inline]
+fn add_to_histograms<T, K>(histograms: &mut [Vec<usize>], item: &T, p: &Params)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let default_mask = item.default_mask(p.radix);
+    let shift = item.usize_to_keytype(p.radix);
+    let mut v = item.into_key_type();
+    for level in (0..p.max_level).rev() {
+        histograms[level][item.keytype_to_usize(v & default_mask)] += 1;
+        if level > 0 {
+            v = v >> shift;
+        }
+    }
+}
+
+/// # Collect sorted
+///
+/// Collect an iterator into a sorted vector.
+///
+/// The histograms of the LSD sort are computed while the items are
+/// collected, so the data is not read a second time to compute them.
+///
+/// The LSD sort and the fallback are stable, so the collect sort is stable.
+pub fn collect_sorted<I, T, K>(iter: I) -> Vec<T>
+where
+    I: Iterator<Item = T>,
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let mut iter = iter;
+    let first = match iter.next() {
+        Some(first) => first,
+        None => return Vec::new(),
+    };
+
+    let p = full_params(&first);
+    let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
+    let mut arr = Vec::with_capacity(iter.size_hint().0 + 1);
+
+    for item in once(first).chain(iter) {
+        add_to_histograms(&mut histograms, &item, &p);
+        arr.push(item);
+    }
+
+    if arr.len() <= FALLBACK_THRESHOLD {
+        arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    } else {
+        lsd_radixsort_with_histograms(&mut arr, p, histograms);
+    }
+
+    arr
+}
+
+/// # Parallel collect sorted
+///
+/// Collect a parallel iterator into a sorted vector.
+///
+/// Each thread collects a block with the histograms of its keys, like the
+/// blocks of the Peeka sort. The blocks are then scattered into the final
+/// vector on the most significant digit which has more than one bucket
+/// filled, and the buckets are sorted in parallel.
+///
+/// The parallel collect sort is an unstable sort.
+pub fn par_collect_sorted<I, T, K>(iter: I, thread_n: usize) -> Vec<T>
+where
+    I: ParallelIterator<Item = T>,
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let pool = ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+
+    let blocks: Vec<(Vec<T>, Vec<Vec<usize>>)> = pool.install(|| {
+        iter.fold(
+            || (Vec::new(), Vec::new()),
+            |(mut block, mut histograms): (Vec<T>, Vec<Vec<usize>>), item| {
+                let p = full_params(&item);
+                if histograms.is_empty() {
+                    histograms =
+                        get_empty_histograms(p.max_level, p.radix_range);
+                }
+                add_to_histograms(&mut histograms, &item, &p);
+                block.push(item);
+                (block, histograms)
+            },
+        )
+        .filter(|(block, _)| !block.is_empty())
+        .collect()
+    });
+
+    let size: usize = blocks.iter().map(|(block, _)| block.len()).sum();
+    if size == 0 {
+        return Vec::new();
+    }
+
+    let dummy = blocks[0].0[0];
+    let p = full_params(&dummy);
+    let mut histograms = get_empty_histograms(p.max_level, p.radix_range);
+    for (_, block_histograms) in blocks.iter() {
+        for (global, local) in histograms.iter_mut().zip(block_histograms) {
+            for (g, l) in global.iter_mut().zip(local) {
+                *g += *l;
+            }
+        }
+    }
+
+    let mut arr = vec![dummy; size];
+    let level =
+        (0..p.max_level).find(|l| !only_one_bucket_filled(&histograms[*l]));
+
+    let level = match level {
+        Some(level) if size > FALLBACK_THRESHOLD => level,
+        _ => {
+            let mut position = 0;
+            for (block, _) in blocks.iter() {
+                arr[position..position + block.len()].copy_from_slice(block);
+                position += block.len();
+            }
+            if level.is_some() {
+                arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            }
+            return arr;
+        },
+    };
+
+    let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
+
+    // Each block owns one region per bucket in the final vector.
+    {
+        let mut regions: Vec<Vec<&mut [T]>> =
+            blocks.iter().map(|_| Vec::with_capacity(p.radix_range)).collect();
+        let mut rest: &mut [T] = &mut arr;
+        for bucket in 0..p.radix_range {
+            for (block_regions, (_, block_histograms)) in
+                regions.iter_mut().zip(blocks.iter())
+            {
+                let count = block_histograms[level][bucket];
+                let (region, tail) =
+                    std::mem::take(&mut rest).split_at_mut(count);
+                block_regions.push(region);
+                rest = tail;
+            }
+        }
+
+        pool.install(|| {
+            blocks.par_iter().zip(regions.into_par_iter()).for_each(
+                |((block, _), mut block_regions)| {
+                    let mut heads = vec![0; p.radix_range];
+                    for item in block.iter() {
+                        let bucket = item.extract(mask, shift);
+                        block_regions[bucket][heads[bucket]] = *item;
+                        heads[bucket] += 1;
+                    }
+                },
+            );
+        });
+    }
+
+    if level < p.max_level - 1 {
+        let mut buckets: Vec<&mut [T]> = Vec::new();
+        let mut rest: &mut [T] = &mut arr;
+        for count in histograms[level].iter() {
+            let (bucket, tail) =
+                std::mem::take(&mut rest).split_at_mut(*count);
+            if bucket.len() > 1 {
+                buckets.push(bucket);
+            }
+            rest = tail;
+        }
+
+        pool.install(|| {
+            buckets.into_par_iter().for_each(|bucket| bucket.voracious_sort());
+        });
+    }
+
+    arr
+}
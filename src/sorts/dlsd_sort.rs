@@ -78,7 +78,7 @@ pub fn dlsd_radixsort_body<T: Radixable<K>, K: RadixKey>(
             continue;
         }
 
-        let (mut source, mut destination) =
+        let (source, mut destination) =
             if index == 0 { (t1, t2) } else { (t2, t1) };
         let (mask, shift) = if diversion {
             dummy.get_mask_and_shift_from_left(&p.new_level(level))
@@ -89,7 +89,7 @@ pub fn dlsd_radixsort_body<T: Radixable<K>, K: RadixKey>(
 
         copy_by_histogram(
             size,
-            &mut source,
+            source,
             &mut destination,
             &mut heads,
             mask,
@@ -17,13 +17,27 @@ where
         return;
     }
 
+    let dummy = arr[0];
+    let histograms = dummy.get_full_histograms(arr, &p);
+
+    lsd_radixsort_with_histograms(arr, p, histograms);
+}
+
+// The histograms must have been computed with the same params, level by
+// level, like `get_full_histograms` does.
+pub fn lsd_radixsort_with_histograms<T, K>(
+    arr: &mut [T],
+    p: Params,
+    histograms: Vec<Vec<usize>>,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
     let size = arr.len();
     let dummy = arr[0];
     let mut buffer: Vec<T> = vec![arr[0]; size];
     let mut index = 0;
 
-    let histograms = dummy.get_full_histograms(arr, &p);
-
     let mut t1 = arr;
     let t2 = &mut buffer;
     let mut t2 = t2.as_mut_slice();
@@ -33,14 +47,14 @@ where
             continue;
         }
 
-        let (mut source, mut destination) =
+        let (source, mut destination) =
             if index == 0 { (t1, t2) } else { (t2, t1) };
         let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
         let (_, mut heads, _) = prefix_sums(&histograms[level]);
 
         copy_by_histogram(
             source.len(),
-            &mut source,
+            source,
             &mut destination,
             &mut heads,
             mask,
@@ -1,5 +1,9 @@
 pub mod american_flag_sort;
+pub mod approx_sort;
 pub mod boolean_sort;
+pub mod cached_key_sort;
+pub mod cluster;
+pub mod collect_sort;
 pub mod comparative_sort;
 pub mod counting_sort;
 pub mod dlsd_sort;
@@ -7,8 +11,12 @@ pub mod lsd_sort;
 pub mod lsd_stable_sort;
 pub mod msd_sort;
 pub mod msd_stable_sort;
+pub mod partition;
 pub mod peeka_sort;
+pub mod radix_partition;
+pub mod range_sort;
 pub mod rollercoaster_sort;
+pub mod sort_by_bits;
 pub mod ska_sort;
 pub mod thiel_sort;
 pub mod utils;
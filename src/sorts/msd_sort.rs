@@ -8,7 +8,7 @@ const UNROLL_SIZE: usize = 4;
 #[inline]
 pub fn copy_by_histogram<T, K>(
     size: usize,
-    source: &mut [T],
+    source: &[T],
     destination: &mut [T],
     heads: &mut Vec<usize>,
     mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
@@ -17,15 +17,32 @@ pub fn copy_by_histogram<T, K>(
     T: Radixable<K>,
     K: RadixKey,
 {
-    let source = &mut source[0..size];
+    let bucket = |item: &T| item.extract(mask, shift);
+    copy_by_histogram_by(size, source, destination, heads, &bucket);
+}
+
+// Same as `copy_by_histogram`, with the bucket of each item given by
+// `bucket`.
+#[inline]
+pub fn copy_by_histogram_by<T, F>(
+    size: usize,
+    source: &[T],
+    destination: &mut [T],
+    heads: &mut Vec<usize>,
+    bucket: &F,
+) where
+    T: Copy,
+    F: Fn(&T) -> usize,
+{
+    let source = &source[0..size];
     let remainder = size % UNROLL_SIZE;
-    let (source_fst, source_snd) = source.split_at_mut(size - remainder);
+    let (source_fst, source_snd) = source.split_at(size - remainder);
 
     source_fst.chunks_exact(UNROLL_SIZE).for_each(|chunk| unsafe {
-        let b0 = chunk.get_unchecked(0).extract(mask, shift);
-        let b1 = chunk.get_unchecked(1).extract(mask, shift);
-        let b2 = chunk.get_unchecked(2).extract(mask, shift);
-        let b3 = chunk.get_unchecked(3).extract(mask, shift);
+        let b0 = bucket(chunk.get_unchecked(0));
+        let b1 = bucket(chunk.get_unchecked(1));
+        let b2 = bucket(chunk.get_unchecked(2));
+        let b3 = bucket(chunk.get_unchecked(3));
 
         let d0 = *heads.get_unchecked(b0);
         heads[b0] += 1;
@@ -43,7 +60,7 @@ pub fn copy_by_histogram<T, K>(
     });
 
     for item in source_snd.iter() {
-        let target_bucket = item.extract(mask, shift);
+        let target_bucket = bucket(item);
         destination[heads[target_bucket]] = *item;
         heads[target_bucket] += 1;
     }
@@ -63,9 +80,9 @@ pub fn msd_radixsort_rec<T: Radixable<K>, K: RadixKey>(
     let histogram = get_histogram(arr, &p, mask, shift);
     let (p_sums, mut heads, _) = prefix_sums(&histogram);
 
-    let mut buffer = arr.to_vec();
+    let buffer = arr.to_vec();
 
-    copy_by_histogram(arr.len(), &mut buffer, arr, &mut heads, mask, shift);
+    copy_by_histogram(arr.len(), &buffer, arr, &mut heads, mask, shift);
 
     let mut rest = arr;
     if p.level < p.max_level - 1 {
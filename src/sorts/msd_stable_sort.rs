@@ -1,5 +1,5 @@
 use super::super::algo::k_way_merge::k_way_merge;
-use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing;
+use super::super::algo::verge_sort_heuristic::verge_sort_preprocessing_stable;
 use super::super::{RadixKey, Radixable};
 use super::msd_sort::copy_by_histogram;
 use super::utils::{get_histogram, prefix_sums, Params};
@@ -20,9 +20,9 @@ fn msd_stable_radixsort_rec<T: Radixable<K>, K: RadixKey>(
     let histogram = get_histogram(arr, &p, mask, shift);
     let (p_sums, mut heads, _) = prefix_sums(&histogram);
 
-    let mut buffer = arr.to_vec();
+    let buffer = arr.to_vec();
 
-    copy_by_histogram(arr.len(), &mut buffer, arr, &mut heads, mask, shift);
+    copy_by_histogram(arr.len(), &buffer, arr, &mut heads, mask, shift);
 
     let mut rest = arr;
     if p.level < p.max_level - 1 {
@@ -86,6 +86,6 @@ pub fn msd_stable_radixsort<T: Radixable<K>, K: RadixKey>(
     }
 
     let mut separators =
-        verge_sort_preprocessing(arr, radix, &msd_stable_radixsort_aux);
+        verge_sort_preprocessing_stable(arr, radix, &msd_stable_radixsort_aux);
     k_way_merge(arr, &mut separators);
 }
@@ -0,0 +1,228 @@
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use super::msd_sort::copy_by_histogram_by;
+use super::ska_sort::ska_swap_by;
+use super::utils::{get_histogram_by, prefix_sums};
+
+const PARALLEL_THRESHOLD: usize = 20_000;
+
+// The bucket is checked on every call: the passes index the histogram, so a
+// bucket function which is not deterministic panics instead of writing out
+// of the array.
+fn checked_bucket<'a, T, F>(
+    bucket_n: usize,
+    bucket: &'a F,
+) -> impl Fn(&T) -> usize + 'a
+where
+    F: Fn(&T) -> usize,
+{
+    move |item| {
+        let b = bucket(item);
+        assert!(
+            b < bucket_n,
+            "[Partition] The bucket {} is out of range ({} buckets).",
+            b,
+            bucket_n
+        );
+        b
+    }
+}
+
+fn stable_partition<T, F>(
+    arr: &mut [T],
+    bucket_n: usize,
+    bucket: &F,
+) -> Vec<usize>
+where
+    T: Copy,
+    F: Fn(&T) -> usize,
+{
+    let bucket = checked_bucket(bucket_n, bucket);
+    let histogram = get_histogram_by(arr, bucket_n, &bucket);
+    let (_, mut heads, _) = prefix_sums(&histogram);
+    let source = arr.to_vec();
+    copy_by_histogram_by(source.len(), &source, arr, &mut heads, &bucket);
+
+    histogram
+}
+
+/// # Voracious partition by
+///
+/// Partition the array into `bucket_n` buckets, given by `bucket(item)`,
+/// and return the boundaries of the buckets: the items of bucket `i` are
+/// `arr[b[i]..b[i + 1]]`, with `bucket_n + 1` boundaries.
+///
+/// The items are counted by bucket, then copied into their bucket from a
+/// copy of the array. The partition is stable and out of place. `bucket` is
+/// called twice per item, and must return the same bucket each time.
+///
+/// Panics if a bucket is not lower than `bucket_n`.
+///
+/// ```
+/// use voracious_radix_sort::voracious_partition_by;
+///
+/// let mut arr = vec![(7, 'a'), (3, 'b'), (4, 'c'), (9, 'd'), (0, 'e')];
+/// let boundaries = voracious_partition_by(&mut arr, 3, |x| x.0 / 4);
+/// assert_eq!(boundaries, vec![0, 2, 4, 5]);
+/// assert_eq!(arr, vec![(3, 'b'), (0, 'e'), (7, 'a'), (4, 'c'), (9, 'd')]);
+/// ```
+pub fn voracious_partition_by<T, F>(
+    arr: &mut [T],
+    bucket_n: usize,
+    bucket: F,
+) -> Vec<usize>
+where
+    T: Copy,
+    F: Fn(&T) -> usize,
+{
+    let histogram = stable_partition(arr, bucket_n, &bucket);
+    let (p_sums, _, _) = prefix_sums(&histogram);
+
+    p_sums
+}
+
+/// # Voracious partition by unstable
+///
+/// Same as the Voracious partition by, but the items are swapped into their
+/// bucket, like in the Ska sort. The partition is in place and unstable, and
+/// the items do not need to be `Copy`.
+pub fn voracious_partition_by_unstable<T, F>(
+    arr: &mut [T],
+    bucket_n: usize,
+    bucket: F,
+) -> Vec<usize>
+where
+    F: Fn(&T) -> usize,
+{
+    let bucket = checked_bucket(bucket_n, &bucket);
+    let histogram = get_histogram_by(arr, bucket_n, &bucket);
+    let (p_sums, mut heads, tails) = prefix_sums(&histogram);
+    ska_swap_by(arr, &mut heads, &tails, &bucket);
+
+    p_sums
+}
+
+/// # Voracious partition by multithread
+///
+/// Same as the Voracious partition by, with several threads.
+///
+/// Each block of the array is partitioned by a thread, then each bucket is
+/// filled by a thread, with the parts of this bucket of every block, in the
+/// order of the blocks. The partition is stable and out of place.
+///
+/// For small arrays, it fallbacks on the single thread partition.
+pub fn voracious_partition_by_mt<T, F>(
+    arr: &mut [T],
+    bucket_n: usize,
+    bucket: F,
+    thread_n: usize,
+) -> Vec<usize>
+where
+    T: Copy + Send + Sync,
+    F: Fn(&T) -> usize + Sync,
+{
+    if arr.len() <= PARALLEL_THRESHOLD {
+        return voracious_partition_by(arr, bucket_n, bucket);
+    }
+
+    let pool = ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+    let block_size = (arr.len() / thread_n.max(1)).max(PARALLEL_THRESHOLD);
+
+    pool.install(|| {
+        let histograms: Vec<Vec<usize>> = arr
+            .par_chunks_mut(block_size)
+            .map(|block| stable_partition(block, bucket_n, &bucket))
+            .collect();
+
+        let mut histogram = vec![0; bucket_n];
+        for block_histogram in histograms.iter() {
+            for (count, block_count) in
+                histogram.iter_mut().zip(block_histogram.iter())
+            {
+                *count += block_count;
+            }
+        }
+        // Start of each bucket of each block in the array.
+        let block_heads: Vec<Vec<usize>> = histograms
+            .iter()
+            .enumerate()
+            .map(|(block, block_histogram)| {
+                let (_, heads, _) = prefix_sums(block_histogram);
+                heads.iter().map(|head| head + block * block_size).collect()
+            })
+            .collect();
+
+        let source = arr.to_vec();
+        let mut buckets = Vec::with_capacity(bucket_n);
+        let mut rest = &mut arr[..];
+        for count in histogram.iter() {
+            let (current, next) = rest.split_at_mut(*count);
+            buckets.push(current);
+            rest = next;
+        }
+
+        buckets.into_par_iter().enumerate().for_each(|(b, destination)| {
+            let mut position = 0;
+            for (block_histogram, heads) in
+                histograms.iter().zip(block_heads.iter())
+            {
+                let count = block_histogram[b];
+                destination[position..position + count]
+                    .copy_from_slice(&source[heads[b]..heads[b] + count]);
+                position += count;
+            }
+        });
+
+        let (p_sums, _, _) = prefix_sums(&histogram);
+        p_sums
+    })
+}
+
+/// # Voracious partition stable
+///
+/// Move the items which satisfy `pred` before the other items, and return
+/// the number of items which satisfy it. The partition is stable and out of
+/// place.
+///
+/// ```
+/// use voracious_radix_sort::voracious_partition_stable;
+///
+/// let mut arr = vec![1, 2, 3, 4, 5, 6];
+/// let split = voracious_partition_stable(&mut arr, |x| x % 3 == 0);
+/// assert_eq!(split, 2);
+/// assert_eq!(arr, vec![3, 6, 1, 2, 4, 5]);
+/// ```
+pub fn voracious_partition_stable<T, P>(arr: &mut [T], pred: P) -> usize
+where
+    T: Copy,
+    P: Fn(&T) -> bool,
+{
+    voracious_partition_by(arr, 2, |item| !pred(item) as usize)[1]
+}
+
+/// # Voracious partition unstable
+///
+/// Same as the Voracious partition stable, but the partition is in place and
+/// unstable.
+pub fn voracious_partition_unstable<T, P>(arr: &mut [T], pred: P) -> usize
+where
+    P: Fn(&T) -> bool,
+{
+    voracious_partition_by_unstable(arr, 2, |item| !pred(item) as usize)[1]
+}
+
+/// # Voracious partition stable multithread
+///
+/// Same as the Voracious partition stable, with several threads.
+pub fn voracious_partition_stable_mt<T, P>(
+    arr: &mut [T],
+    pred: P,
+    thread_n: usize,
+) -> usize
+where
+    T: Copy + Send + Sync,
+    P: Fn(&T) -> bool + Sync,
+{
+    voracious_partition_by_mt(arr, 2, |item| !pred(item) as usize, thread_n)[1]
+}
@@ -57,17 +57,16 @@ where
     histograms
 }
 
-fn peeka_sort_rec<T: Radixable<K>, K: RadixKey>(
+// Scatter the array on the digit of the level `p.level`, and return the
+// boundaries of the buckets. The buckets are sorted recursively if `recurse`
+// is true.
+pub(crate) fn regions_sort_level<T: Radixable<K>, K: RadixKey>(
     arr: &mut [T],
     p: Params,
     pool: &ThreadPool,
     block_size: usize,
-) {
-    if arr.len() <= FALLBACK_THRESHOLD {
-        fallback(arr, p);
-        return;
-    }
-
+    recurse: bool,
+) -> Vec<usize> {
     let dummy = arr[0];
 
     // Local Sorting Phase for each block
@@ -135,7 +134,7 @@ fn peeka_sort_rec<T: Radixable<K>, K: RadixKey>(
                 bro_offset,
             );
 
-            if p.level < p.max_level - 1 {
+            if recurse {
                 if broker.len() > 3000 {
                     s.spawn(move |_| {
                         let new_params = p.new_level(p.level + 1);
@@ -156,6 +155,23 @@ fn peeka_sort_rec<T: Radixable<K>, K: RadixKey>(
             fallback(&mut small_array, p.new_level(p.level + 1));
         }
     });
+
+    p_sums
+}
+
+fn peeka_sort_rec<T: Radixable<K>, K: RadixKey>(
+    arr: &mut [T],
+    p: Params,
+    pool: &ThreadPool,
+    block_size: usize,
+) {
+    if arr.len() <= FALLBACK_THRESHOLD {
+        fallback(arr, p);
+        return;
+    }
+
+    let recurse = p.level < p.max_level - 1;
+    regions_sort_level(arr, p, pool, block_size, recurse);
 }
 
 /// # Peek Regions sort (Peekasort)
@@ -0,0 +1,145 @@
+use rayon::ThreadPoolBuilder;
+
+use super::super::{RadixKey, Radixable};
+use super::msd_sort::copy_by_histogram;
+use super::peeka_sort::regions_sort_level;
+use super::ska_sort::ska_swap;
+use super::utils::{compute_max_level, get_histogram, prefix_sums, Params};
+
+const MAX_RADIX: usize = 16;
+const PARALLEL_THRESHOLD: usize = 20_000;
+
+fn check_radix(radix: usize) {
+    assert!(
+        radix > 0 && radix <= MAX_RADIX,
+        "[Radix partition] The radix must be between 1 and {}.",
+        MAX_RADIX
+    );
+}
+
+fn partition_params<T, K>(dummy: &T, bits_from: usize, radix: usize) -> Params
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let bits = dummy.type_size();
+    assert!(
+        bits_from < bits,
+        "[Radix partition] bits_from must be lower than the key size ({} \
+         bits).",
+        bits
+    );
+
+    Params::new(0, radix, bits_from, compute_max_level(bits, bits_from, radix))
+}
+
+/// # Radix partition
+///
+/// Scatter the array on one digit of the key, and return the boundaries of
+/// the buckets: the items of bucket `i` are `arr[b[i]..b[i + 1]]`, with
+/// `2^radix + 1` boundaries.
+///
+/// The digit is made of the `radix` bits of the key which follow the
+/// `bits_from` most significant bits. If the key has less than
+/// `bits_from + radix` bits, the digit is made of the remaining bits.
+///
+/// This is one pass of the Ska sort: the partition is inplace and unstable.
+///
+/// ```
+/// use voracious_radix_sort::radix_partition;
+///
+/// let mut arr: Vec<u8> = vec![0xF1, 0x12, 0xA0, 0x1F, 0x05];
+/// let boundaries = radix_partition(&mut arr, 0, 4);
+/// // Bucket 1 holds the items whose 4 most significant bits are 0x1.
+/// assert_eq!(&boundaries[1..3], &[1, 3]);
+/// assert!(arr[1..3].iter().all(|x| x >> 4 == 1));
+/// ```
+pub fn radix_partition<T, K>(
+    arr: &mut [T],
+    bits_from: usize,
+    radix: usize,
+) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    check_radix(radix);
+    if arr.is_empty() {
+        return vec![0; (1 << radix) + 1];
+    }
+
+    let dummy = arr[0];
+    let p = partition_params(&dummy, bits_from, radix);
+    let (mask, shift) = dummy.get_mask_and_shift_from_left(&p);
+    let histogram = get_histogram(arr, &p, mask, shift);
+    let (p_sums, mut heads, tails) = prefix_sums(&histogram);
+
+    ska_swap(arr, &mut heads, &tails, mask, shift);
+
+    p_sums
+}
+
+/// # Radix partition stable
+///
+/// Same as the Radix partition, but the items of a bucket keep their relative
+/// order.
+///
+/// This is one pass of the MSD sort: the partition is out of place and
+/// stable.
+pub fn radix_partition_stable<T, K>(
+    arr: &mut [T],
+    bits_from: usize,
+    radix: usize,
+) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    check_radix(radix);
+    if arr.is_empty() {
+        return vec![0; (1 << radix) + 1];
+    }
+
+    let dummy = arr[0];
+    let p = partition_params(&dummy, bits_from, radix);
+    let (mask, shift) = dummy.get_mask_and_shift_from_left(&p);
+    let histogram = get_histogram(arr, &p, mask, shift);
+    let (p_sums, mut heads, _) = prefix_sums(&histogram);
+
+    let buffer = arr.to_vec();
+    copy_by_histogram(arr.len(), &buffer, arr, &mut heads, mask, shift);
+
+    p_sums
+}
+
+/// # Radix partition multithread
+///
+/// Same as the Radix partition, with several threads.
+///
+/// This is one pass of the Peeka sort: each block of the array is
+/// partitioned by a thread, then the blocks are swapped into their buckets
+/// with the regions graph. The partition is inplace and unstable.
+///
+/// For small arrays, it fallbacks on the single thread Radix partition.
+pub fn radix_partition_mt<T, K>(
+    arr: &mut [T],
+    bits_from: usize,
+    radix: usize,
+    thread_n: usize,
+) -> Vec<usize>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    check_radix(radix);
+    if arr.len() <= PARALLEL_THRESHOLD {
+        return radix_partition(arr, bits_from, radix);
+    }
+
+    let dummy = arr[0];
+    let p = partition_params(&dummy, bits_from, radix);
+    let pool = ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+    let block_size = (arr.len() / thread_n.max(1)).max(PARALLEL_THRESHOLD);
+
+    regions_sort_level(arr, p, &pool, block_size, false)
+}
@@ -0,0 +1,288 @@
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+
+use std::marker::PhantomData;
+use std::mem::size_of;
+
+use super::super::algo::permutation::{apply_permutation, PermIndex};
+use super::super::traits::radix_key::key_to_u128;
+use super::super::{RadixKey, Radixable};
+use super::cached_key_sort::{
+    run_cached_key_task, sort_cached_keys, CachedKey, CachedKeyTask, Mode,
+    INDIRECT_SIZE,
+};
+use super::partition::{
+    voracious_partition_by_mt, voracious_partition_by_unstable,
+};
+
+const RADIX: usize = 8;
+const RADIX_RANGE: usize = 1 << RADIX;
+const SMALL_SIZE: usize = 64;
+const PARALLEL_THRESHOLD: usize = 100_000;
+
+#[inline]
+fn item_key<T, K>(item: &T) -> u128
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    key_to_u128(&item.key())
+}
+
+// Smallest and largest keys, in one pass.
+fn key_range<T, K>(arr: &[T]) -> (u128, u128)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    arr.iter().fold((u128::MAX, 0), |(min, max), item| {
+        let key = item_key(item);
+        (min.min(key), max.max(key))
+    })
+}
+
+fn key_range_mt<T, K>(arr: &[T]) -> (u128, u128)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    arr.par_iter()
+        .fold(
+            || (u128::MAX, 0),
+            |(min, max), item| {
+                let key = item_key(item);
+                (min.min(key), max.max(key))
+            },
+        )
+        .reduce(
+            || (u128::MAX, 0),
+            |(min_a, max_a), (min_b, max_b)| {
+                (min_a.min(min_b), max_a.max(max_b))
+            },
+        )
+}
+
+// Sorts the items through (key - min, index) pairs.
+struct RangeSortPairs<'a, T, K> {
+    arr: &'a mut [T],
+    min: u128,
+    mode: Mode,
+    key: PhantomData<fn() -> K>,
+}
+
+impl<'a, T, K> CachedKeyTask for RangeSortPairs<'a, T, K>
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    type Output = ();
+
+    fn run<U, I>(self, narrow: fn(u128) -> U)
+    where
+        CachedKey<U, I>: Radixable<U>,
+        U: RadixKey,
+        I: PermIndex,
+    {
+        let min = self.min;
+        let mut keys: Vec<CachedKey<U, I>> = self
+            .arr
+            .iter()
+            .enumerate()
+            .map(|(index, item)| CachedKey {
+                key: narrow(item_key(item).wrapping_sub(min)),
+                index: I::from_usize(index),
+            })
+            .collect();
+
+        sort_cached_keys(&mut keys, self.mode);
+
+        let mut perm: Vec<I> = keys.iter().map(|cached| cached.index).collect();
+        drop(keys);
+        apply_permutation(self.arr, &mut perm);
+    }
+}
+
+#[inline]
+fn range_digit<T, K>(item: &T, min: u128, shift: usize) -> usize
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    ((item_key(item).wrapping_sub(min) >> shift) as usize) & (RADIX_RANGE - 1)
+}
+
+// MSD radix sort on the digits of `key - min`, from the digit at `shift`
+// down: only the bits of the range are sorted, wherever it lies.
+fn range_sort_direct_rec<T, K>(arr: &mut [T], min: u128, shift: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= SMALL_SIZE {
+        arr.sort_unstable_by_key(|item| item_key(item).wrapping_sub(min));
+        return;
+    }
+
+    let bucket = |item: &T| range_digit(item, min, shift);
+    let p_sums = voracious_partition_by_unstable(arr, RADIX_RANGE, bucket);
+    if shift == 0 {
+        return;
+    }
+
+    let mut rest = arr;
+    for bounds in p_sums.windows(2) {
+        let (bucket, next) = rest.split_at_mut(bounds[1] - bounds[0]);
+        rest = next;
+        if bucket.len() > 1 {
+            range_sort_direct_rec(bucket, min, shift - RADIX);
+        }
+    }
+}
+
+// The first digit is partitioned with several threads, then the buckets are
+// sorted in parallel.
+fn range_sort_direct_mt<T, K>(
+    arr: &mut [T],
+    min: u128,
+    shift: usize,
+    thread_n: usize,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let bucket = |item: &T| range_digit(item, min, shift);
+    let p_sums = voracious_partition_by_mt(arr, RADIX_RANGE, bucket, thread_n);
+    if shift == 0 {
+        return;
+    }
+
+    let mut buckets = Vec::with_capacity(RADIX_RANGE);
+    let mut rest = arr;
+    for bounds in p_sums.windows(2) {
+        let (bucket, next) = rest.split_at_mut(bounds[1] - bounds[0]);
+        rest = next;
+        if bucket.len() > 1 {
+            buckets.push(bucket);
+        }
+    }
+
+    let pool = ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+    pool.install(|| {
+        buckets.into_par_iter().for_each(|bucket| {
+            range_sort_direct_rec(bucket, min, shift - RADIX)
+        })
+    });
+}
+
+fn range_sort_direct<T, K>(arr: &mut [T], min: u128, max: u128, mode: Mode)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let bits = 128 - (max - min).leading_zeros() as usize;
+    if bits == 0 {
+        return;
+    }
+
+    // The digits are aligned on the lowest bit, the first one may be partial.
+    let shift = (bits - 1) / RADIX * RADIX;
+    match mode {
+        Mode::Multithread(thread_n) if arr.len() > PARALLEL_THRESHOLD => {
+            range_sort_direct_mt(arr, min, shift, thread_n)
+        },
+        _ => range_sort_direct_rec(arr, min, shift),
+    }
+}
+
+// Panics if the range given by the user is empty. The keys are only checked
+// with the `checked` feature, since the scan is the one the known bounds save.
+pub(crate) fn check_range<T, K>(arr: &[T], min: u128, max: u128)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(min <= max, "[Range sort] The minimum is above the maximum.");
+    if cfg!(feature = "checked") {
+        for item in arr.iter() {
+            let key = item_key(item);
+            assert!(
+                key >= min && key <= max,
+                "[Range sort] The key of an item is out of the range."
+            );
+        }
+    }
+}
+
+// Small items are sorted directly, with the offset of the range. Large items
+// are sorted through (key - min, index) pairs, on the smallest integer which
+// holds `max - min`.
+pub(crate) fn range_sort_dispatch<T, K>(
+    arr: &mut [T],
+    min: u128,
+    max: u128,
+    mode: Mode,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() < 2 {
+        return;
+    }
+
+    if size_of::<T>() < INDIRECT_SIZE {
+        range_sort_direct(arr, min, max, mode);
+        return;
+    }
+
+    let bits = 128 - (max - min).leading_zeros() as usize;
+    let len = arr.len();
+    let task = RangeSortPairs { arr, min, mode, key: PhantomData };
+    run_cached_key_task(bits, len, task);
+}
+
+/// # Range sort
+///
+/// Sort an array whose keys lie in a narrow range, far from zero, such as
+/// timestamps.
+///
+/// The smallest and the largest keys are found in one pass. The smallest key
+/// is subtracted from every key when its digits are read, thus only the
+/// digits of `max - min` are sorted, even when the range straddles a power of
+/// two, whereas the other radix sorts only skip the leading zero bits of the
+/// largest key. Items of 64 bytes or more are sorted as (key - min, index)
+/// pairs instead, on the smallest integer which holds the range, and moved in
+/// place once.
+///
+/// The Range sort is an unstable radix sort.
+///
+/// ```
+/// use voracious_radix_sort::range_sort;
+///
+/// let base: u64 = 1 << 40;
+/// let mut arr = vec![base + 7, base + 2, base + 5, base];
+/// range_sort(&mut arr);
+/// assert_eq!(arr, vec![base, base + 2, base + 5, base + 7]);
+/// ```
+pub fn range_sort<T, K>(arr: &mut [T])
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let (min, max) = key_range(arr);
+    range_sort_dispatch(arr, min, max, Mode::Unstable);
+}
+
+/// # Range multithread sort
+///
+/// Same as the Range sort, but the range is found and the first digit is
+/// partitioned with several threads, then the buckets are sorted in
+/// parallel.
+pub fn range_mt_sort<T, K>(arr: &mut [T], thread_n: usize)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let pool = ThreadPoolBuilder::new().num_threads(thread_n).build().unwrap();
+    let (min, max) = pool.install(|| key_range_mt(arr));
+    range_sort_dispatch(arr, min, max, Mode::Multithread(thread_n));
+}
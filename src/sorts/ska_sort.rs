@@ -12,6 +12,19 @@ pub fn ska_swap<T: Radixable<K>, K: RadixKey>(
     mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
     shift: usize,
 ) {
+    ska_swap_by(arr, heads, tails, &|item: &T| item.extract(mask, shift));
+}
+
+// Same as `ska_swap`, with the bucket of each item given by `bucket`. The
+// items only need to be swapped, they do not need to be Copy.
+pub fn ska_swap_by<T, F>(
+    arr: &mut [T],
+    heads: &mut Vec<usize>,
+    tails: &[usize],
+    bucket: &F,
+) where
+    F: Fn(&T) -> usize,
+{
     let mut buckets_size = Vec::new();
     for i in 0..heads.len() {
         buckets_size.push((i, tails[i] - heads[i]))
@@ -33,10 +46,10 @@ pub fn ska_swap<T: Radixable<K>, K: RadixKey>(
                     let o = offset + q * UNROLL_SIZE;
 
                     unsafe {
-                        let tb0 = arr.get_unchecked(o).extract(mask, shift);
-                        let tb1 = arr.get_unchecked(o + 1).extract(mask, shift);
-                        let tb2 = arr.get_unchecked(o + 2).extract(mask, shift);
-                        let tb3 = arr.get_unchecked(o + 3).extract(mask, shift);
+                        let tb0 = bucket(arr.get_unchecked(o));
+                        let tb1 = bucket(arr.get_unchecked(o + 1));
+                        let tb2 = bucket(arr.get_unchecked(o + 2));
+                        let tb3 = bucket(arr.get_unchecked(o + 3));
 
                         let dest_index_0 = *heads.get_unchecked(tb0);
                         heads[tb0] += 1;
@@ -58,7 +71,7 @@ pub fn ska_swap<T: Radixable<K>, K: RadixKey>(
 
                 for i in 0..remainder {
                     unsafe {
-                        let b = arr.get_unchecked(n_o + i).extract(mask, shift);
+                        let b = bucket(arr.get_unchecked(n_o + i));
                         arr.swap(n_o + i, heads[b]);
                         heads[b] += 1;
                     }
@@ -0,0 +1,132 @@
+use std::ops::Range;
+
+use super::super::traits::radix_key::key_to_u128;
+use super::super::{RadixKey, Radixable};
+use super::radix_partition::{radix_partition, radix_partition_stable};
+
+const RADIX: usize = 8;
+const SMALL_SIZE: usize = 64;
+
+// The bits `bits_from..bits_to` of the key, counted from the most
+// significant bit.
+#[inline]
+pub(crate) fn bit_field<T, K>(item: &T, bits_from: usize, bits_to: usize) -> u128
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    let width = bits_to - bits_from;
+    let field = key_to_u128(&item.key()) >> (item.type_size() - bits_to);
+    if width == 128 {
+        field
+    } else {
+        field & ((1 << width) - 1)
+    }
+}
+
+fn sort_by_bits_rec<T, K>(
+    arr: &mut [T],
+    bits_from: usize,
+    bits_to: usize,
+    stable: bool,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() < 2 || bits_from >= bits_to {
+        return;
+    }
+
+    let by_field = |item: &T| bit_field(item, bits_from, bits_to);
+    if arr.len() <= SMALL_SIZE {
+        if stable {
+            arr.sort_by_key(by_field);
+        } else {
+            arr.sort_unstable_by_key(by_field);
+        }
+        return;
+    }
+
+    // The last digit only holds the remaining bits of the range.
+    let radix = (bits_to - bits_from).min(RADIX);
+    let boundaries = if stable {
+        radix_partition_stable(arr, bits_from, radix)
+    } else {
+        radix_partition(arr, bits_from, radix)
+    };
+
+    for bucket in boundaries.windows(2) {
+        sort_by_bits_rec(
+            &mut arr[bucket[0]..bucket[1]],
+            bits_from + radix,
+            bits_to,
+            stable,
+        );
+    }
+}
+
+fn check_bit_range<T, K>(dummy: &T, bit_range: &Range<usize>)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    assert!(
+        bit_range.start <= bit_range.end && bit_range.end <= dummy.type_size(),
+        "[Sort by bits] The bit range {:?} is not in the key ({} bits).",
+        bit_range,
+        dummy.type_size()
+    );
+}
+
+/// # Voracious sort by bits
+///
+/// Sort the array by a range of bits of the key only, counted from the most
+/// significant bit: `0..20` are the 20 most significant bits. The order of
+/// the items whose bits are equal is unspecified.
+///
+/// This is a MSD radix sort which starts at the offset `bit_range.start` and
+/// stops after the last bit of the range, thus it is cheaper than a full
+/// sort. The sort is inplace and unstable.
+///
+/// Panics if the range is not in the key.
+///
+/// ```
+/// use voracious_radix_sort::voracious_sort_by_bits;
+///
+/// let mut arr: Vec<u16> = vec![0x3A00, 0x1F00, 0x3100, 0x10FF];
+/// // Sort by the 4 most significant bits only.
+/// voracious_sort_by_bits(&mut arr, 0..4);
+/// assert!(arr[..2].iter().all(|x| x >> 12 == 1));
+/// assert!(arr[2..].iter().all(|x| x >> 12 == 3));
+/// ```
+pub fn voracious_sort_by_bits<T, K>(arr: &mut [T], bit_range: Range<usize>)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.is_empty() {
+        return;
+    }
+
+    check_bit_range(&arr[0], &bit_range);
+    sort_by_bits_rec(arr, bit_range.start, bit_range.end, false);
+}
+
+/// # Voracious stable sort by bits
+///
+/// Same as the Voracious sort by bits, but the items whose bits are equal
+/// keep their relative order. The sort is out of place.
+pub fn voracious_stable_sort_by_bits<T, K>(
+    arr: &mut [T],
+    bit_range: Range<usize>,
+) where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.is_empty() {
+        return;
+    }
+
+    check_bit_range(&arr[0], &bit_range);
+    sort_by_bits_rec(arr, bit_range.start, bit_range.end, true);
+}
@@ -483,14 +483,14 @@ where
             continue;
         }
 
-        let (mut source, mut destination) =
+        let (source, mut destination) =
             if index == 0 { (t1, t2) } else { (t2, t1) };
         let (mask, shift) = dummy.get_mask_and_shift(&p.new_level(level));
         let (_, mut heads, _) = prefix_sums(&histograms[level]);
 
         copy_by_histogram(
             size,
-            &mut source,
+            source,
             &mut destination,
             &mut heads,
             mask,
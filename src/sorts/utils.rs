@@ -76,7 +76,7 @@ pub fn only_one_bucket_filled(histogram: &[usize]) -> bool {
 }
 
 pub fn offset_from_bits<T, K>(
-    _arr: &mut [T],
+    _arr: &[T],
     biggest: <<T as Radixable<K>>::Key as RadixKey>::Key,
     radix: usize,
     bits: usize,
@@ -112,7 +112,7 @@ where
 }
 
 pub fn compute_offset<T: Radixable<K> + Copy, K: RadixKey>(
-    arr: &mut [T],
+    arr: &[T],
     radix: usize,
 ) -> (usize, usize) {
     let dummy = arr[0];
@@ -153,20 +153,32 @@ pub fn get_empty_histograms(
 }
 
 pub fn get_histogram<T: Radixable<K>, K: RadixKey>(
-    arr: &mut [T],
+    arr: &[T],
     p: &Params,
     mask: <<T as Radixable<K>>::Key as RadixKey>::Key,
     shift: usize,
 ) -> Vec<usize> {
-    let mut histogram = vec![0; p.radix_range];
+    get_histogram_by(arr, p.radix_range, &|item: &T| item.extract(mask, shift))
+}
+
+// Same as `get_histogram`, with the bucket of each item given by `bucket`.
+pub fn get_histogram_by<T, F>(
+    arr: &[T],
+    bucket_n: usize,
+    bucket: &F,
+) -> Vec<usize>
+where
+    F: Fn(&T) -> usize,
+{
+    let mut histogram = vec![0; bucket_n];
     let remainder = arr.len() % 4;
     let (arr_fst, arr_remainder) = arr.split_at(arr.len() - remainder);
 
     arr_fst.chunks_exact(4).for_each(|chunk| {
-        let bucket0 = chunk[0].extract(mask, shift);
-        let bucket1 = chunk[1].extract(mask, shift);
-        let bucket2 = chunk[2].extract(mask, shift);
-        let bucket3 = chunk[3].extract(mask, shift);
+        let bucket0 = bucket(&chunk[0]);
+        let bucket1 = bucket(&chunk[1]);
+        let bucket2 = bucket(&chunk[2]);
+        let bucket3 = bucket(&chunk[3]);
         histogram[bucket0] += 1;
         histogram[bucket1] += 1;
         histogram[bucket2] += 1;
@@ -174,8 +186,8 @@ pub fn get_histogram<T: Radixable<K>, K: RadixKey>(
     });
 
     arr_remainder.iter().for_each(|item| {
-        let bucket = item.extract(mask, shift);
-        histogram[bucket] += 1;
+        let b = bucket(item);
+        histogram[b] += 1;
     });
 
     histogram
@@ -385,7 +397,7 @@ pub fn get_partial_histograms<T: Radixable<K>, K: RadixKey>(
     histograms
 }
 
-pub fn get_full_histograms<T, K>(arr: &mut [T], p: &Params) -> Vec<Vec<usize>>
+pub fn get_full_histograms<T, K>(arr: &[T], p: &Params) -> Vec<Vec<usize>>
 where
     T: Radixable<K>,
     K: RadixKey,
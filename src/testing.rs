@@ -0,0 +1,344 @@
+//! # Conformance test kit
+//!
+//! Helpers to check a user implementation of `Radixable`.
+//!
+//! `check_radixable` verifies that the methods of the trait agree with each
+//! other and with `PartialOrd`, then runs every raw sort of the crate against
+//! a reference sort. `check_counting_sort` verifies `to_generic`, which is
+//! only required by the Counting sort.
+//!
+//! The helpers panic with a message naming the failed check, they are meant
+//! to be called in tests:
+//!
+//! ```
+//! use voracious_radix_sort::testing::check_radixable;
+//!
+//! let samples: Vec<u32> = (0..5_000).map(|i| (i * 7_919) % 1_000).collect();
+//! check_radixable(&samples);
+//! ```
+//!
+//! The samples should cover the keys used by your application. The raw sorts
+//! fallback on comparative sorts for small inputs, so the samples are also
+//! repeated up to a few thousand items, to go through the radix paths.
+//!
+//! The stability is checked with the `Debug` representation of the items: it
+//! is only observable if `Debug` shows more than the key.
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::mem::size_of;
+
+use super::algo::checked::{check_pair, CheckError};
+use super::sorts::utils::{get_full_histograms, Params};
+use super::{
+    american_flag_sort, collect_sorted, counting_sort, dlsd_radixsort,
+    lsd_radixsort, lsd_stable_radixsort, msd_radixsort, msd_stable_radixsort,
+    peeka_sort, rollercoaster_sort, ska_sort, thiel_radixsort, voracious_sort,
+};
+use super::{RadixKey, RadixSort, Radixable};
+
+const RADIX: usize = 8;
+const REPEATED_SIZE: usize = 20_000;
+const PAIRS_SAMPLE: usize = 256;
+
+type RawSort<T> = (&'static str, bool, Box<dyn Fn(&mut [T])>);
+
+fn raw_sorts<T: Radixable<K>, K: RadixKey>() -> Vec<RawSort<T>> {
+    vec![
+        (
+            "american_flag_sort",
+            false,
+            Box::new(|a| american_flag_sort(a, RADIX)),
+        ),
+        (
+            "collect_sorted",
+            true,
+            Box::new(|a: &mut [T]| {
+                let sorted = collect_sorted(a.iter().copied());
+                a.copy_from_slice(&sorted);
+            }),
+        ),
+        ("dlsd_radixsort", false, Box::new(|a| dlsd_radixsort(a, RADIX))),
+        ("lsd_radixsort", false, Box::new(|a| lsd_radixsort(a, RADIX))),
+        (
+            "lsd_stable_radixsort",
+            true,
+            Box::new(|a| lsd_stable_radixsort(a, RADIX)),
+        ),
+        ("msd_radixsort", false, Box::new(|a| msd_radixsort(a, RADIX))),
+        (
+            "msd_stable_radixsort",
+            true,
+            Box::new(|a| msd_stable_radixsort(a, RADIX)),
+        ),
+        ("peeka_sort", false, Box::new(|a| peeka_sort(a, RADIX, 2_000, 2))),
+        (
+            "rollercoaster_sort",
+            false,
+            Box::new(|a| rollercoaster_sort(a, RADIX)),
+        ),
+        ("ska_sort", false, Box::new(|a| ska_sort(a, RADIX))),
+        ("thiel_radixsort", false, Box::new(|a| thiel_radixsort(a, RADIX))),
+        ("voracious_sort", false, Box::new(|a| voracious_sort(a, RADIX))),
+        ("RadixSort::voracious_sort", false, Box::new(|a| a.voracious_sort())),
+        (
+            "RadixSort::voracious_stable_sort",
+            true,
+            Box::new(|a| a.voracious_stable_sort()),
+        ),
+        (
+            "RadixSort::voracious_mt_sort",
+            false,
+            Box::new(|a| a.voracious_mt_sort(2)),
+        ),
+    ]
+}
+
+fn full_params<T: Radixable<K>, K: RadixKey>(dummy: &T) -> Params {
+    Params::new(0, RADIX, 0, dummy.compute_max_level(0, RADIX))
+}
+
+fn check_type_size<T, K>(samples: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let width = size_of::<<<T as Radixable<K>>::Key as RadixKey>::Key>() * 8;
+    for item in samples.iter() {
+        let bits = item.type_size();
+        assert!(
+            bits > 0 && bits <= width,
+            "[check_radixable] type_size: {} bits for {:?}, but the key type \
+             has {} bits.",
+            bits,
+            item,
+            width
+        );
+        if bits < width {
+            let high = item.into_key_type() >> item.usize_to_keytype(bits);
+            assert!(
+                high == item.default_key(),
+                "[check_radixable] type_size: the key of {:?} has bits set \
+                 above type_size ({} bits).",
+                item,
+                bits
+            );
+        }
+    }
+}
+
+// The digits given by `get_mask_and_shift` and `extract` must be the digits
+// of the key, aligned on the least significant bit. The digits given by
+// `get_mask_and_shift_from_left` must be the digits of the key, aligned on
+// the most significant bit of `type_size`.
+fn check_digits<T, K>(samples: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    for item in samples.iter() {
+        let p = full_params(item);
+        let key = item.into_key_type();
+        let default_mask = item.default_mask(RADIX);
+        let bits = item.type_size();
+
+        for level in 0..p.max_level {
+            let (mask, shift) = item.get_mask_and_shift(&p.new_level(level));
+            let bit = RADIX * (p.max_level - level - 1);
+            let expected = item.keytype_to_usize(
+                (key >> item.usize_to_keytype(bit)) & default_mask,
+            );
+            assert_eq!(
+                item.extract(mask, shift),
+                expected,
+                "[check_radixable] get_mask_and_shift/extract: wrong digit at \
+                 level {} for {:?}.",
+                level,
+                item
+            );
+
+            let (mask, shift) =
+                item.get_mask_and_shift_from_left(&p.new_level(level));
+            let top = RADIX * (level + 1);
+            let expected = if top <= bits {
+                (key >> item.usize_to_keytype(bits - top)) & default_mask
+            } else {
+                key & (default_mask >> item.usize_to_keytype(top - bits))
+            };
+            assert_eq!(
+                item.extract(mask, shift),
+                item.keytype_to_usize(expected),
+                "[check_radixable] get_mask_and_shift_from_left/extract: \
+                 wrong digit at level {} for {:?}.",
+                level,
+                item
+            );
+        }
+    }
+}
+
+fn check_histograms<T, K>(samples: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let mut arr = samples.to_vec();
+    let dummy = arr[0];
+    let p = full_params(&dummy);
+    let histograms = dummy.get_full_histograms(&mut arr, &p);
+    let expected = get_full_histograms(&arr, &p);
+    assert!(
+        histograms == expected,
+        "[check_radixable] get_full_histograms: the histograms differ from \
+         the histograms of the keys."
+    );
+}
+
+fn check_order<T, K>(samples: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let step = (samples.len() / PAIRS_SAMPLE).max(1);
+    for left in samples.iter().step_by(step) {
+        for right in samples.iter().step_by(step) {
+            if let Err(CheckError::InconsistentOrder {
+                left,
+                right,
+                partial_cmp,
+                key_order,
+            }) = check_pair(left, right)
+            {
+                panic!(
+                    "[check_radixable] key/PartialOrd: {:?} and {:?} are \
+                     {:?} according to partial_cmp, but {:?} according to \
+                     the keys.",
+                    left, right, partial_cmp, key_order
+                );
+            }
+        }
+    }
+}
+
+fn check_sorts<T, K>(input: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let mut reference = input.to_vec();
+    reference.sort_by_key(|item| item.into_key_type());
+
+    for (name, stable, sort) in raw_sorts::<T, K>().iter() {
+        let mut arr = input.to_vec();
+        sort(&mut arr);
+
+        for (i, (item, expected)) in
+            arr.iter().zip(reference.iter()).enumerate()
+        {
+            assert!(
+                item.into_key_type() == expected.into_key_type(),
+                "[check_radixable] {}: wrong key at index {} (size {}): {:?} \
+                 instead of {:?}.",
+                name,
+                i,
+                arr.len(),
+                item,
+                expected
+            );
+            assert!(
+                !*stable || format!("{:?}", item) == format!("{:?}", expected),
+                "[check_radixable] {}: not stable at index {} (size {}): \
+                 {:?} instead of {:?}.",
+                name,
+                i,
+                arr.len(),
+                item,
+                expected
+            );
+        }
+    }
+}
+
+/// # Check Radixable
+///
+/// Check a `Radixable` implementation on the given samples:
+/// - `type_size` covers all the bits of the keys.
+/// - `get_mask_and_shift`, `get_mask_and_shift_from_left` and `extract` give
+///   the digits of the key.
+/// - `get_full_histograms` gives the histograms of the digits.
+/// - `partial_cmp` agrees with the key order, on a sample of pairs.
+/// - All the raw sorts give the same keys as a reference sort, and the stable
+///   sorts give the same items, compared with `Debug`.
+///
+/// Panics with a message naming the failed check.
+pub fn check_radixable<T, K>(samples: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    if samples.is_empty() {
+        return;
+    }
+
+    check_type_size(samples);
+    check_digits(samples);
+    check_histograms(samples);
+    check_order(samples);
+
+    check_sorts(samples);
+    if samples.len() < REPEATED_SIZE {
+        let repeated: Vec<T> =
+            samples.iter().copied().cycle().take(REPEATED_SIZE).collect();
+        check_sorts(&repeated);
+    }
+}
+
+/// # Check Counting sort
+///
+/// Check that `to_generic` is the inverse of the key, and that the Counting
+/// sort sorts the samples. The key must be at most 16 bits long.
+///
+/// Panics with a message naming the failed check.
+pub fn check_counting_sort<T, K>(samples: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    if samples.is_empty() {
+        return;
+    }
+
+    let bits = samples[0].type_size();
+    assert!(
+        bits <= 16,
+        "[check_counting_sort] type_size: {} bits, the Counting sort needs at \
+         most 16 bits.",
+        bits
+    );
+
+    for item in samples.iter() {
+        let generic =
+            item.to_generic(item.keytype_to_usize(item.into_key_type()));
+        assert!(
+            generic.into_key_type() == item.into_key_type()
+                && generic.partial_cmp(item) == Some(Ordering::Equal),
+            "[check_counting_sort] to_generic: {:?} gives {:?}.",
+            item,
+            generic
+        );
+    }
+
+    let mut arr = samples.to_vec();
+    let mut reference = samples.to_vec();
+    counting_sort(&mut arr, bits);
+    reference.sort_by_key(|item| item.into_key_type());
+    for (i, (item, expected)) in arr.iter().zip(reference.iter()).enumerate() {
+        assert!(
+            item.into_key_type() == expected.into_key_type(),
+            "[check_counting_sort] counting_sort: wrong key at index {}: {:?} \
+             instead of {:?}.",
+            i,
+            item,
+            expected
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt::Debug;
+
+use super::super::{
+    voracious_sort_approx, voracious_sort_approx_cleanup, RadixKey, Radixable,
+};
+use super::sort_by_bits::field;
+
+use super::super::generators::float_32::*;
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i32::*;
+use super::super::generators::unsigned_u64::*;
+
+fn helper_sort_approx<T, K>(input: &[T], precision_bits: usize)
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let mut check = input.to_vec();
+    check.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut arr = input.to_vec();
+    voracious_sort_approx(&mut arr, precision_bits);
+    if !input.is_empty() {
+        // The significant bits start after the leading zeros of every key.
+        let bits = input[0].type_size();
+        let biggest = input.iter().map(|x| field(x, &(0..bits))).max();
+        let offset = biggest.unwrap().leading_zeros() as usize - (128 - bits);
+        let bit_range = offset..(offset + precision_bits).min(bits);
+        // Every item is within its bucket of its sorted position.
+        for (item, sorted) in arr.iter().zip(check.iter()) {
+            assert_eq!(field(item, &bit_range), field(sorted, &bit_range));
+        }
+    }
+
+    let mut arr = input.to_vec();
+    voracious_sort_approx_cleanup(&mut arr, precision_bits);
+    assert_eq!(arr, check);
+}
+
+#[test]
+fn test_sort_approx() {
+    for size in [0, 1, 50, 5_000, 100_000].iter() {
+        for precision_bits in [1, 4, 12, 20, 64].iter() {
+            let precision_bits = *precision_bits;
+            helper_sort_approx(
+                &helper_random_array_uniform_f32(*size),
+                precision_bits,
+            );
+            helper_sort_approx(
+                &helper_random_array_uniform_f64(*size),
+                precision_bits,
+            );
+            helper_sort_approx(
+                &helper_random_array_uniform_u64(*size),
+                precision_bits,
+            );
+            helper_sort_approx(
+                &helper_random_array_uniform_i32(*size),
+                precision_bits,
+            );
+            helper_sort_approx(
+                &helper_random_array_small_f32(*size),
+                precision_bits,
+            );
+            helper_sort_approx(
+                &helper_random_array_uniform_10_9_u64(*size),
+                precision_bits,
+            );
+        }
+    }
+}
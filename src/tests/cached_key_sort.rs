@@ -0,0 +1,123 @@
+use std::cmp::Ordering;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+use rand::{thread_rng, Rng};
+
+use super::super::sorts::cached_key_sort::{
+    cached_key_mt_sort, cached_key_sort, cached_key_stable_sort,
+    CACHED_KEYS_MIN_LEN,
+};
+use super::super::{RadixSort, Radixable};
+use super::sorts::helper_sort;
+
+use super::super::generators::boolean::*;
+use super::super::generators::custom::*;
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i32::*;
+use super::super::generators::unsigned_u128::*;
+use super::super::generators::unsigned_u8::*;
+
+// Only used by one test, so the counter is not shared between tests.
+static KEY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+// The key is decoded from big endian bytes, each call is counted.
+#[derive(Copy, Clone, Debug)]
+struct Encoded {
+    bytes: [u8; 8],
+    tag: usize,
+}
+
+impl Encoded {
+    fn decode(&self) -> u64 { u64::from_be_bytes(self.bytes) }
+}
+
+impl PartialOrd for Encoded {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.decode().partial_cmp(&other.decode())
+    }
+}
+
+impl PartialEq for Encoded {
+    fn eq(&self, other: &Self) -> bool { self.decode() == other.decode() }
+}
+
+impl Radixable<u64> for Encoded {
+    type Key = u64;
+    #[inline]
+    fn key(&self) -> Self::Key {
+        KEY_CALLS.fetch_add(1, AtomicOrdering::Relaxed);
+        self.decode()
+    }
+    #[inline]
+    fn cache_key(&self) -> bool { true }
+}
+
+fn helper_random_array_encoded(size: usize) -> Vec<Encoded> {
+    let mut rng = thread_rng();
+    (0..size)
+        .map(|tag| {
+            let value: u64 = rng.gen_range(0, 1 << 40);
+            Encoded { bytes: value.to_be_bytes(), tag }
+        })
+        .collect()
+}
+
+fn key_calls(sort: &dyn Fn(&mut Vec<Encoded>), arr: &mut Vec<Encoded>) -> usize {
+    KEY_CALLS.store(0, AtomicOrdering::Relaxed);
+    sort(arr);
+    KEY_CALLS.load(AtomicOrdering::Relaxed)
+}
+
+// The `checked` feature calls `key()` again to verify the sorted array, so
+// only the extraction can be counted exactly without it. Small arrays are
+// sorted directly, by comparisons.
+fn assert_key_calls(calls: usize, size: usize) {
+    if size < CACHED_KEYS_MIN_LEN {
+        return;
+    }
+    if cfg!(feature = "checked") {
+        assert!(calls >= size);
+    } else {
+        assert_eq!(calls, size);
+    }
+}
+
+#[test]
+fn test_cached_key_sort_calls_key_once() {
+    for size in [0, 1, 100, 10_000, 100_000].iter() {
+        let arr = helper_random_array_encoded(*size);
+        let mut check = arr.to_vec();
+        check.sort_by_key(|item| item.decode());
+
+        let mut sorted = arr.to_vec();
+        assert_key_calls(key_calls(&|a| a.voracious_sort(), &mut sorted), *size);
+        assert_eq!(sorted, check);
+
+        let mut sorted = arr.to_vec();
+        assert_key_calls(key_calls(&|a| a.voracious_mt_sort(2), &mut sorted), *size);
+        assert_eq!(sorted, check);
+
+        // The stable sort keeps the order of the tags.
+        let mut sorted = arr.to_vec();
+        assert_key_calls(key_calls(&|a| a.voracious_stable_sort(), &mut sorted), *size);
+        let tags: Vec<usize> = sorted.iter().map(|item| item.tag).collect();
+        let check_tags: Vec<usize> = check.iter().map(|item| item.tag).collect();
+        assert_eq!(tags, check_tags);
+    }
+}
+
+#[test]
+fn test_cached_key_sort_key_widths() {
+    for size in [0, 1, 500, 50_000].iter() {
+        helper_sort(false, &|a| cached_key_sort(a), generators_bool(), *size);
+        helper_sort(false, &|a| cached_key_sort(a), generators_u8(), *size);
+        helper_sort(false, &|a| cached_key_sort(a), generators_i32(), *size);
+        helper_sort(false, &|a| cached_key_sort(a), generators_f64(), *size);
+        helper_sort(false, &|a| cached_key_sort(a), generators_u128(), *size);
+        helper_sort(false, &|a| cached_key_sort(a), generators_structf64(), *size);
+        helper_sort(false, &|a| cached_key_sort(a), generators_structi128(), *size);
+        helper_sort(false, &|a| cached_key_stable_sort(a), generators_structu32(), *size);
+        helper_sort(false, &|a| cached_key_mt_sort(a, 2), generators_structi64(), *size);
+        helper_sort(false, &|a| a.voracious_sort_by_cached_key(), generators_structu16(), *size);
+    }
+}
@@ -0,0 +1,107 @@
+use std::cmp::Ordering;
+
+use super::super::algo::checked::{check_key_order, check_sorted, CheckError};
+use super::super::traits::checked_sort::RadixSortChecked;
+use super::super::Radixable;
+
+use super::super::generators::float_64::*;
+use super::super::generators::unsigned_u32::*;
+
+// The key is the opposite of the order given by PartialOrd.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Inconsistent {
+    value: u32,
+}
+
+impl PartialOrd for Inconsistent {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.value.partial_cmp(&self.value)
+    }
+}
+
+impl Radixable<u32> for Inconsistent {
+    type Key = u32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+#[test]
+fn test_checked_sort_ok() {
+    for size in [0, 1, 100, 10_000, 200_000].iter() {
+        let mut arr = helper_random_array_uniform_u32(*size);
+        assert_eq!(arr.voracious_sort_checked(), Ok(()));
+        let mut arr = helper_random_array_uniform_u32(*size);
+        assert_eq!(arr.voracious_stable_sort_checked(), Ok(()));
+        let mut arr = helper_random_array_uniform_u32(*size);
+        assert_eq!(arr.voracious_mt_sort_checked(2), Ok(()));
+        let mut arr = helper_random_array_uniform_f64(*size);
+        assert_eq!(arr.as_mut_slice().voracious_sort_checked(), Ok(()));
+    }
+}
+
+#[test]
+fn test_checked_sort_not_sorted() {
+    let arr = vec![1, 2, 3, 9, 4, 5];
+    assert_eq!(
+        check_sorted(&arr),
+        Err(CheckError::NotSorted { index: 3, left: 9, right: 4 })
+    );
+
+    // Parallel check.
+    let mut arr: Vec<u32> = (0..300_000).collect();
+    arr.swap(150_000, 250_000);
+    match check_sorted(&arr) {
+        Err(CheckError::NotSorted { index, left, right }) => {
+            assert_eq!(index, 150_000);
+            assert_eq!((left, right), (250_000, 150_001));
+        },
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[test]
+fn test_checked_sort_inconsistent_key() {
+    let mut arr: Vec<Inconsistent> =
+        (0..1_000).map(|value| Inconsistent { value }).collect();
+    match arr.voracious_sort_checked() {
+        Err(CheckError::InconsistentOrder {
+            left,
+            right,
+            partial_cmp,
+            key_order,
+        }) => {
+            assert_eq!(left.partial_cmp(&right), Some(partial_cmp));
+            assert_eq!(left.value.cmp(&right.value), key_order);
+            assert_ne!(partial_cmp, key_order);
+        },
+        other => panic!("unexpected result: {:?}", other),
+    }
+
+    // Equal items and NaN are not reported.
+    let arr = vec![1.0, 1.0, f64::NAN, 2.0];
+    assert_eq!(check_key_order(&arr), Ok(()));
+}
+
+#[test]
+fn test_checked_sort_error_message() {
+    let error: CheckError<u32> =
+        CheckError::NotSorted { index: 3, left: 9, right: 4 };
+    assert_eq!(
+        error.to_string(),
+        "[voracious_sort] Output not sorted: the item at index 3 is greater \
+         than the next item. Left: 9, right: 4."
+    );
+
+    let error = CheckError::InconsistentOrder {
+        left: Inconsistent { value: 1 },
+        right: Inconsistent { value: 2 },
+        partial_cmp: Ordering::Greater,
+        key_order: Ordering::Less,
+    };
+    assert_eq!(
+        error.to_string(),
+        "[voracious_sort] Radixable key and PartialOrd disagree: partial_cmp \
+         gives Greater but the keys give Less. Left: Inconsistent { value: 1 \
+         }, right: Inconsistent { value: 2 }."
+    );
+}
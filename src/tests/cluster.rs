@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use rand::{thread_rng, Rng};
+
+use super::super::sorts::cluster::fmix64;
+use super::super::{
+    voracious_cluster, voracious_cluster_mt, RadixKey, Radixable,
+};
+
+use super::super::generators::unsigned_u128::*;
+use super::super::generators::unsigned_u64::*;
+
+fn helper_check_groups<T, K>(arr: &[T], input: &[T], groups: &[usize])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+    <<T as Radixable<K>>::Key as RadixKey>::Key: Hash + Eq,
+{
+    // Each group holds a single key, and each key is in a single group.
+    let mut counts = HashMap::new();
+    for item in input.iter() {
+        *counts.entry(item.into_key_type()).or_insert(0) += 1;
+    }
+    assert_eq!(groups.len(), counts.len() + 1);
+    assert_eq!(groups.first(), Some(&0));
+    assert_eq!(groups.last(), Some(&arr.len()));
+    for group in groups.windows(2) {
+        let key = arr[group[0]].into_key_type();
+        assert!(arr[group[0]..group[1]]
+            .iter()
+            .all(|x| x.into_key_type() == key));
+        assert_eq!(counts.remove(&key), Some(group[1] - group[0]));
+    }
+}
+
+fn helper_cluster<T, K>(input: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+    <<T as Radixable<K>>::Key as RadixKey>::Key: Hash + Eq,
+{
+    let mut arr = input.to_vec();
+    let groups = voracious_cluster(&mut arr);
+    helper_check_groups(&arr, input, &groups);
+
+    let mut arr = input.to_vec();
+    let groups = voracious_cluster_mt(&mut arr, 4);
+    helper_check_groups(&arr, input, &groups);
+}
+
+#[test]
+fn test_cluster() {
+    let mut rng = thread_rng();
+    for size in [0, 1, 500, 30_000, 200_000].iter() {
+        helper_cluster(&helper_random_array_uniform_u64(*size));
+        helper_cluster(&helper_random_array_uniform_u128(*size));
+        for cardinality in [1_u64, 10, 5_000].iter() {
+            let arr: Vec<u64> = (0..*size)
+                .map(|_| rng.gen_range(0, *cardinality) << 40)
+                .collect();
+            helper_cluster(&arr);
+            let low = -(*cardinality as i32);
+            let arr: Vec<i32> =
+                (0..*size).map(|_| rng.gen_range(low, 1)).collect();
+            helper_cluster(&arr);
+        }
+    }
+}
+
+#[test]
+fn test_cluster_floats() {
+    let mut arr = vec![0.5_f64, -1.0, 0.5, 2.0, -1.0, 0.5];
+    let groups = voracious_cluster(&mut arr);
+    assert_eq!(groups.len(), 4);
+    let mut sizes: Vec<usize> =
+        groups.windows(2).map(|g| g[1] - g[0]).collect();
+    sizes.sort();
+    assert_eq!(sizes, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_cluster_hash_collisions() {
+    // The high and the low halves of a u128 key are mixed by a xor, so keys
+    // with the same low half mixed with the hash of the high half collide.
+    let mut rng = thread_rng();
+    let low: u64 = rng.gen();
+    let input: Vec<u128> = (0..50_000)
+        .map(|_| {
+            let high: u64 = rng.gen_range(0, 4);
+            ((high as u128) << 64) | (low ^ fmix64(high)) as u128
+        })
+        .collect();
+    helper_cluster(&input);
+}
@@ -0,0 +1,48 @@
+use rayon::prelude::*;
+
+use super::super::types::custom::StructU64;
+use super::super::{ParVoraciousSorted, VoraciousSorted};
+
+use super::super::generators::custom::*;
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i32::*;
+use super::super::generators::unsigned_u128::*;
+use super::super::generators::unsigned_u64::*;
+use super::super::generators::unsigned_u8::*;
+
+use super::sorts::helper_sort;
+
+#[test]
+fn test_collect_sort_voracious_sorted() {
+    for size in [0, 1, 200, 5_000, 50_000].iter() {
+        helper_sort(true, &|a| *a = a.iter().copied().voracious_sorted(), generators_u8(), *size);
+        helper_sort(true, &|a| *a = a.iter().copied().voracious_sorted(), generators_u64(), *size);
+        helper_sort(true, &|a| *a = a.iter().copied().voracious_sorted(), generators_u128(), *size);
+        helper_sort(true, &|a| *a = a.iter().copied().voracious_sorted(), generators_i32(), *size);
+        helper_sort(true, &|a| *a = a.iter().copied().voracious_sorted(), generators_f64(), *size);
+        helper_sort(true, &|a| *a = a.iter().copied().voracious_sorted(), generators_structu64(), *size);
+    }
+}
+
+#[test]
+fn test_collect_sort_par_collect_voracious_sorted() {
+    for size in [0, 1, 200, 5_000, 100_000].iter() {
+        helper_sort(false, &|a| *a = a.par_iter().copied().par_collect_voracious_sorted(4), generators_u8(), *size);
+        helper_sort(false, &|a| *a = a.par_iter().copied().par_collect_voracious_sorted(4), generators_u64(), *size);
+        helper_sort(false, &|a| *a = a.par_iter().copied().par_collect_voracious_sorted(4), generators_u128(), *size);
+        helper_sort(false, &|a| *a = a.par_iter().copied().par_collect_voracious_sorted(4), generators_i32(), *size);
+        helper_sort(false, &|a| *a = a.par_iter().copied().par_collect_voracious_sorted(4), generators_f64(), *size);
+        helper_sort(false, &|a| *a = a.par_iter().copied().par_collect_voracious_sorted(4), generators_structu64(), *size);
+    }
+}
+
+#[test]
+fn test_collect_sort_stable() {
+    let sorted: Vec<StructU64> = (0..20_000)
+        .map(|i| StructU64 { value: (i * 7919 % 300) as u64, other: i })
+        .voracious_sorted();
+    assert!(sorted.windows(2).all(|w| {
+        w[0].value < w[1].value
+            || (w[0].value == w[1].value && w[0].other < w[1].other)
+    }));
+}
@@ -0,0 +1,109 @@
+use rand::{thread_rng, Rng};
+
+use super::super::{sort_columns, Column, Payload, SortKeySpec};
+
+type Row = (u8, i32, f64, Option<u16>, u128, usize);
+
+fn helper_random_rows(size: usize) -> Vec<Row> {
+    let mut rng = thread_rng();
+    (0..size)
+        .map(|tag| {
+            let maybe = if rng.gen_bool(0.2) {
+                None
+            } else {
+                Some(rng.gen_range(0, 8))
+            };
+            (
+                rng.gen_range(0, 4),
+                rng.gen_range(-50, 50),
+                rng.gen_range(-2, 2) as f64 / 4.0,
+                maybe,
+                rng.gen_range(0, 3) << 100,
+                tag,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_sort_columns() {
+    for size in [0, 1, 2, 500, 50_000].iter() {
+        let rows = helper_random_rows(*size);
+
+        let mut region: Vec<u8> = rows.iter().map(|r| r.0).collect();
+        let mut score: Vec<i32> = rows.iter().map(|r| r.1).collect();
+        let mut ratio: Vec<f64> = rows.iter().map(|r| r.2).collect();
+        let mut maybe: Vec<Option<u16>> = rows.iter().map(|r| r.3).collect();
+        let mut wide: Vec<u128> = rows.iter().map(|r| r.4).collect();
+        let mut names: Vec<String> =
+            rows.iter().map(|r| r.5.to_string()).collect();
+
+        // ORDER BY region, score DESC, maybe DESC, wide, ratio DESC
+        let specs = [
+            SortKeySpec::asc(0),
+            SortKeySpec::desc(1),
+            SortKeySpec::desc(3),
+            SortKeySpec::asc(4),
+            SortKeySpec::desc(2),
+        ];
+        let mut payload = Payload(&mut names);
+        let mut columns: Vec<&mut dyn Column> = vec![
+            &mut region,
+            &mut score,
+            &mut ratio,
+            &mut maybe,
+            &mut wide,
+            &mut payload,
+        ];
+        sort_columns(&mut columns, &specs);
+
+        let mut check = rows.to_vec();
+        check.sort_by(|a, b| {
+            a.0.cmp(&b.0)
+                .then(b.1.cmp(&a.1))
+                .then(b.3.cmp(&a.3))
+                .then(a.4.cmp(&b.4))
+                .then(b.2.partial_cmp(&a.2).unwrap())
+        });
+
+        assert_eq!(region, check.iter().map(|r| r.0).collect::<Vec<_>>());
+        assert_eq!(score, check.iter().map(|r| r.1).collect::<Vec<_>>());
+        assert_eq!(ratio, check.iter().map(|r| r.2).collect::<Vec<_>>());
+        assert_eq!(maybe, check.iter().map(|r| r.3).collect::<Vec<_>>());
+        assert_eq!(wide, check.iter().map(|r| r.4).collect::<Vec<_>>());
+        // The sort is stable: the ties keep the order of the input.
+        let expected: Vec<String> =
+            check.iter().map(|r| r.5.to_string()).collect();
+        assert_eq!(names, expected);
+    }
+}
+
+#[test]
+fn test_sort_columns_without_specs() {
+    let mut a: Vec<u32> = vec![3, 1, 2];
+    let mut b: Vec<u32> = vec![1, 2, 3];
+    sort_columns(&mut [&mut a, &mut b], &[]);
+    assert_eq!(a, vec![3, 1, 2]);
+    assert_eq!(b, vec![1, 2, 3]);
+
+    sort_columns(&mut [&mut b, &mut a], &[SortKeySpec::desc(0)]);
+    assert_eq!(a, vec![2, 1, 3]);
+    assert_eq!(b, vec![3, 2, 1]);
+}
+
+#[test]
+#[should_panic(expected = "The columns must have the same length")]
+fn test_sort_columns_different_lengths() {
+    let mut a: Vec<u32> = vec![3, 1, 2];
+    let mut b: Vec<u32> = vec![1, 2];
+    sort_columns(&mut [&mut a, &mut b], &[SortKeySpec::asc(0)]);
+}
+
+#[test]
+#[should_panic(expected = "The column 1 is not a key column")]
+fn test_sort_columns_payload_spec() {
+    let mut a: Vec<u32> = vec![3, 1, 2];
+    let mut names = vec!["c", "a", "b"];
+    let mut payload = Payload(&mut names);
+    sort_columns(&mut [&mut a, &mut payload], &[SortKeySpec::asc(1)]);
+}
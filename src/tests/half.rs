@@ -0,0 +1,63 @@
+use super::super::{
+    counting_sort, lsd_radixsort, RadixKey, RadixSort, BF16, F16,
+};
+
+use super::super::generators::half::*;
+
+use super::sorts::helper_sort;
+
+// Exact conversion, NaN payloads and signs are kept.
+fn f16_to_f32(h: u16) -> f32 {
+    let sign = ((h >> 15) as u32) << 31;
+    let exponent = ((h >> 10) & 0x1F) as i32;
+    let mantissa = (h & 0x3FF) as u32;
+    if exponent == 0x1F {
+        f32::from_bits(sign | 0x7F80_0000 | (mantissa << 13))
+    } else {
+        let value = if exponent == 0 {
+            mantissa as f32 * 2f32.powi(-24)
+        } else {
+            (0x400 | mantissa) as f32 * 2f32.powi(exponent - 25)
+        };
+        f32::from_bits(sign | value.to_bits())
+    }
+}
+
+#[test]
+fn test_half_order_as_f32() {
+    let mut array: Vec<F16> = (0..=u16::MAX).map(F16).collect();
+    array.voracious_sort();
+    assert!(array.windows(2).all(|w| {
+        f16_to_f32(w[0].0).into_keytype() < f16_to_f32(w[1].0).into_keytype()
+    }));
+
+    let mut array: Vec<BF16> = (0..=u16::MAX).map(BF16).collect();
+    array.voracious_sort();
+    assert!(array.windows(2).all(|w| {
+        let a = f32::from_bits((w[0].0 as u32) << 16);
+        let b = f32::from_bits((w[1].0 as u32) << 16);
+        a.into_keytype() < b.into_keytype()
+    }));
+}
+
+#[test]
+fn test_half_sort() {
+    for size in [0, 1, 10_000].iter() {
+        helper_sort(false, &|a| counting_sort(a, 16), generators_f16(), *size);
+        helper_sort(false, &|a| counting_sort(a, 16), generators_bf16(), *size);
+        helper_sort(false, &|a| lsd_radixsort(a, 8), generators_f16(), *size);
+        helper_sort(false, &|a| lsd_radixsort(a, 8), generators_bf16(), *size);
+    }
+}
+
+#[test]
+fn test_half_trait_voracious() {
+    for size in [0, 1, 200, 5_000, 50_000, 200_000].iter() {
+        helper_sort(false, &|a| a.voracious_sort(), generators_f16(), *size);
+        helper_sort(false, &|a| a.voracious_sort(), generators_bf16(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_f16(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_bf16(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort(4), generators_f16(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort(4), generators_bf16(), *size);
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::Debug;
+
+use super::super::kernels::{
+    compute_offset, copy_by_histogram, digit, full_histograms, histogram,
+    prefix_sums, ska_swap, Params, ParamsError,
+};
+use super::super::{RadixKey, Radixable};
+
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i64::*;
+use super::super::generators::unsigned_u32::*;
+use super::super::generators::unsigned_u64::*;
+
+// A MSD sort built with the kernels only.
+fn kernels_msd_sort<T, K>(arr: &mut [T], p: &Params, stable: bool)
+where
+    T: Radixable<K>,
+    K: RadixKey,
+{
+    if arr.len() <= 1 {
+        return;
+    }
+
+    let h = if stable {
+        let h = histogram(arr, p);
+        let source = arr.to_vec();
+        copy_by_histogram(&source, arr, &h, p);
+        h
+    } else {
+        ska_swap(arr, p)
+    };
+
+    if p.level() + 1 < p.max_level() {
+        let next = p.with_level(p.level() + 1).unwrap();
+        let (p_sums, _, _) = prefix_sums(&h);
+        for bucket in 0..p.radix_range() {
+            kernels_msd_sort(&mut arr[p_sums[bucket]..p_sums[bucket + 1]], &next, stable);
+        }
+    }
+}
+
+fn helper_kernels_msd_sort<T, K>(input: &[T], radix: usize)
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let mut check = input.to_vec();
+    check.sort_by_key(|item| item.into_key_type());
+
+    let (_, offset) = compute_offset(input, radix);
+    let offset = offset.min(input[0].type_size() - 1);
+    let p = Params::new(&input[0], radix, offset).unwrap();
+
+    let mut arr = input.to_vec();
+    kernels_msd_sort(&mut arr, &p, true);
+    assert_eq!(arr, check);
+
+    let mut arr = input.to_vec();
+    kernels_msd_sort(&mut arr, &p, false);
+    let keys: Vec<_> = arr.iter().map(|item| item.into_key_type()).collect();
+    let check_keys: Vec<_> = check.iter().map(|item| item.into_key_type()).collect();
+    assert!(keys == check_keys);
+}
+
+#[test]
+fn test_kernels_params() {
+    let dummy = 0_u32;
+    assert_eq!(Params::new(&dummy, 0, 0).unwrap_err(), ParamsError::Radix { radix: 0 });
+    assert_eq!(Params::new(&dummy, 17, 0).unwrap_err(), ParamsError::Radix { radix: 17 });
+    assert_eq!(
+        Params::new(&dummy, 8, 32).unwrap_err(),
+        ParamsError::Offset { offset: 32, bits: 32 }
+    );
+
+    let p = Params::new(&dummy, 7, 4).unwrap();
+    assert_eq!((p.level(), p.radix(), p.offset()), (0, 7, 4));
+    assert_eq!((p.max_level(), p.radix_range()), (4, 128));
+    assert_eq!(p.with_level(3).unwrap().level(), 3);
+    assert_eq!(
+        p.with_level(4).unwrap_err(),
+        ParamsError::Level { level: 4, max_level: 4 }
+    );
+}
+
+#[test]
+fn test_kernels_msd_sort() {
+    for size in [1, 500, 50_000].iter() {
+        for radix in [3, 8, 11].iter() {
+            helper_kernels_msd_sort(&helper_random_array_uniform_u32(*size), *radix);
+            helper_kernels_msd_sort(&helper_random_array_uniform_10_9_u64(*size), *radix);
+            helper_kernels_msd_sort(&helper_random_array_uniform_i64(*size), *radix);
+            helper_kernels_msd_sort(&helper_random_array_uniform_f64(*size), *radix);
+        }
+    }
+}
+
+#[test]
+fn test_kernels_full_histograms() {
+    let arr = helper_random_array_uniform_u64(10_000);
+    // 8 levels are unrolled, 22 levels are not.
+    for radix in [8, 3].iter() {
+        let p = Params::new(&arr[0], *radix, 0).unwrap();
+        let histograms = full_histograms(&arr, &p);
+        assert_eq!(histograms.len(), p.max_level());
+        for (level, h) in histograms.iter().enumerate() {
+            let shift = radix * (p.max_level() - level - 1);
+            let mut check = vec![0; p.radix_range()];
+            for item in arr.iter() {
+                check[((item >> shift) as usize) & (p.radix_range() - 1)] += 1;
+            }
+            assert_eq!(h, &check);
+        }
+    }
+}
+
+#[test]
+fn test_kernels_digit() {
+    let p = Params::new(&0_u32, 8, 4).unwrap();
+    assert_eq!(digit(&0x0AB0_0000_u32, &p), 0xAB);
+    assert_eq!(digit(&0x0AB0_0000_u32, &p.with_level(3).unwrap()), 0x0);
+    assert_eq!(digit(&0x0000_000F_u32, &p.with_level(3).unwrap()), 0xF);
+    assert_eq!(histogram(&[] as &[u32], &p), vec![0; 256]);
+}
+
+#[test]
+#[should_panic(expected = "The histogram must count every item of the array")]
+fn test_kernels_copy_by_histogram_bad_histogram() {
+    let source = vec![1_u32, 2, 3];
+    let mut destination = vec![0_u32; 3];
+    let p = Params::new(&source[0], 8, 0).unwrap();
+    let mut h = histogram(&source, &p);
+    h[0] += 1;
+    copy_by_histogram(&source, &mut destination, &h, &p);
+}
+
+#[test]
+#[should_panic(expected = "The params were built for keys of another size")]
+fn test_kernels_params_of_another_key() {
+    let p = Params::new(&0_u8, 8, 0).unwrap();
+    let mut arr = vec![1_u32, 2, 3];
+    ska_swap(&mut arr, &p);
+}
@@ -1,7 +1,29 @@
+mod approx_sort;
+#[rustfmt::skip] mod cached_key_sort;
+mod checked;
+mod cluster;
+#[rustfmt::skip] mod collect_sort;
+mod columns;
 mod comparative_sort;
 mod dedicated;
+#[rustfmt::skip] mod half;
+#[rustfmt::skip] mod kernels;
+mod option;
+mod partition;
+mod permutation;
+mod radix_join;
+mod radix_partition;
+mod radix_sorted_vec;
+mod range_sort;
 mod regions_graph;
+mod registry;
+mod sort_by_bits;
+mod sort_by_key;
 #[rustfmt::skip] mod sorts;
+mod stability;
+#[rustfmt::skip] mod std_types;
+mod streaming_sorter;
+mod testing;
 mod types;
 mod utils;
 mod utils_mt;
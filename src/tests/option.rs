@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+
+use rayon::prelude::*;
+
+use super::super::{NoneLast, RadixKey, RadixSort, Radixable};
+
+use super::super::generators::option::*;
+
+use super::sorts::helper_sort;
+
+#[derive(Copy, Clone, Debug)]
+struct StructOptionU64 {
+    value: Option<u64>,
+    other: isize,
+}
+impl PartialOrd for StructOptionU64 {
+    fn partial_cmp(&self, other: &StructOptionU64) -> Option<Ordering> {
+        (self.value).partial_cmp(&(other.value))
+    }
+}
+impl PartialEq for StructOptionU64 {
+    fn eq(&self, other: &Self) -> bool { self.value == other.value }
+}
+impl Radixable<Option<u64>> for StructOptionU64 {
+    type Key = Option<u64>;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+#[test]
+fn test_option_into_keytype() {
+    assert_eq!(None::<u8>.into_keytype(), 0u16);
+    assert_eq!(Some(0u8).into_keytype(), 0b1_0000_0000u16);
+    assert_eq!(Some(255u8).into_keytype(), 0b1_1111_1111u16);
+    assert_eq!(Some(-1i8).into_keytype(), 0b1_0111_1111u16);
+    assert_eq!(RadixKey::type_size(&Some(0u8)), 9);
+    assert_eq!(RadixKey::type_size(&None::<u64>), 65);
+
+    assert_eq!(NoneLast(None::<u8>).into_keytype(), 0b1_0000_0000u16);
+    assert_eq!(NoneLast(Some(0u8)).into_keytype(), 0u16);
+    assert_eq!(NoneLast(Some(255u8)).into_keytype(), 0b0_1111_1111u16);
+}
+
+#[test]
+fn test_option_none_last_order() {
+    let mut array = vec![
+        NoneLast(Some(5)),
+        NoneLast(None),
+        NoneLast(Some(1)),
+        NoneLast(None),
+        NoneLast(Some(3u32)),
+    ];
+    array.voracious_sort();
+    assert_eq!(array, vec![
+        NoneLast(Some(1)),
+        NoneLast(Some(3)),
+        NoneLast(Some(5)),
+        NoneLast(None),
+        NoneLast(None),
+    ]);
+
+    let mut array = vec![Some(5), None, Some(1), None, Some(3u32)];
+    array.voracious_sort();
+    assert_eq!(array, vec![None, None, Some(1), Some(3), Some(5)]);
+}
+
+#[test]
+fn test_option_trait_voracious() {
+    for size in [0, 1, 200, 5_000, 50_000].iter() {
+        helper_sort(false, &|a| a.voracious_sort(), generators_option_u32(), *size);
+        helper_sort(false, &|a| a.voracious_sort(), generators_option_i64(), *size);
+        helper_sort(false, &|a| a.voracious_sort(), generators_option_f64(), *size);
+        helper_sort(false, &|a| a.voracious_sort(), generators_nonelast_u16(), *size);
+    }
+}
+
+#[test]
+fn test_option_trait_voracious_stable() {
+    for size in [0, 1, 200, 5_000, 50_000].iter() {
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_option_u32(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_option_i64(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_option_f64(), *size);
+        helper_sort(true, &|a| a.voracious_stable_sort(), generators_nonelast_u16(), *size);
+    }
+}
+
+#[test]
+fn test_option_trait_voracious_mt_sort() {
+    for size in [0, 1, 10_000, 100_000].iter() {
+        helper_sort(false, &|a| a.voracious_mt_sort(4), generators_option_u32(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort(4), generators_option_i64(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort(4), generators_option_f64(), *size);
+        helper_sort(false, &|a| a.voracious_mt_sort(4), generators_nonelast_u16(), *size);
+    }
+}
+
+#[test]
+fn test_option_struct_key() {
+    let mut array: Vec<StructOptionU64> = helper_random_array_uniform_option_i64(20_000)
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| StructOptionU64 { value: v.map(|v| v as u64), other: i as isize })
+        .collect();
+    let mut check = array.to_vec();
+    array.voracious_stable_sort();
+    check.par_sort_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(array, check);
+    assert!(array.iter().zip(check.iter()).all(|(a, b)| a.other == b.other));
+}
@@ -0,0 +1,104 @@
+use rand::{thread_rng, Rng};
+
+use super::super::{
+    voracious_partition_by, voracious_partition_by_mt,
+    voracious_partition_by_unstable, voracious_partition_stable,
+    voracious_partition_stable_mt, voracious_partition_unstable,
+};
+
+type Partition<'a> = Box<dyn Fn(&mut [(u64, usize)]) -> Vec<usize> + 'a>;
+
+// The second field is the position in the input, to check the stability.
+fn helper_random_pairs(size: usize) -> Vec<(u64, usize)> {
+    let mut rng = thread_rng();
+    (0..size).map(|i| (rng.gen::<u64>(), i)).collect()
+}
+
+fn helper_check_partition_by(input: &[(u64, usize)], bucket_n: usize) {
+    let bucket = |item: &(u64, usize)| (item.0 % bucket_n as u64) as usize;
+
+    let mut histogram = vec![0; bucket_n + 1];
+    for item in input.iter() {
+        histogram[bucket(item) + 1] += 1;
+    }
+    let expected: Vec<usize> = histogram
+        .iter()
+        .scan(0, |sum, count| {
+            *sum += count;
+            Some(*sum)
+        })
+        .collect();
+
+    let mut stable = input.to_vec();
+    stable.sort_by_key(bucket);
+
+    let partitions: Vec<Partition> = vec![
+        Box::new(|a| voracious_partition_by(a, bucket_n, bucket)),
+        Box::new(|a| voracious_partition_by_mt(a, bucket_n, bucket, 4)),
+    ];
+    for partition in partitions.iter() {
+        let mut arr = input.to_vec();
+        assert_eq!(partition(&mut arr), expected);
+        assert_eq!(arr, stable);
+    }
+
+    let mut arr = input.to_vec();
+    let boundaries = voracious_partition_by_unstable(&mut arr, bucket_n, bucket);
+    assert_eq!(boundaries, expected);
+    for b in 0..bucket_n {
+        let items = &arr[boundaries[b]..boundaries[b + 1]];
+        assert!(items.iter().all(|item| bucket(item) == b));
+    }
+    arr.sort_by_key(|item| item.1);
+    assert_eq!(arr, input);
+}
+
+#[test]
+fn test_partition_by() {
+    for size in [0, 1, 500, 30_000, 200_000].iter() {
+        for bucket_n in [1, 2, 7, 1000].iter() {
+            helper_check_partition_by(&helper_random_pairs(*size), *bucket_n);
+        }
+    }
+}
+
+#[test]
+fn test_partition_predicate() {
+    for size in [0, 1, 500, 30_000, 200_000].iter() {
+        let input = helper_random_pairs(*size);
+        let pred = |item: &(u64, usize)| item.0 & 3 == 1;
+
+        let mut check = input.to_vec();
+        check.sort_by_key(|item| !pred(item));
+        let split = check.iter().filter(|item| pred(item)).count();
+
+        let mut arr = input.to_vec();
+        assert_eq!(voracious_partition_stable(&mut arr, pred), split);
+        assert_eq!(arr, check);
+
+        let mut arr = input.to_vec();
+        assert_eq!(voracious_partition_stable_mt(&mut arr, pred, 4), split);
+        assert_eq!(arr, check);
+
+        let mut arr = input.to_vec();
+        assert_eq!(voracious_partition_unstable(&mut arr, pred), split);
+        assert!(arr[..split].iter().all(pred));
+        assert!(!arr[split..].iter().any(pred));
+    }
+}
+
+#[test]
+fn test_partition_unstable_not_copy() {
+    let mut arr: Vec<String> =
+        vec!["bb", "a", "ccc", "dd", "e"].into_iter().map(String::from).collect();
+    let boundaries = voracious_partition_by_unstable(&mut arr, 4, |s| s.len());
+    assert_eq!(boundaries, vec![0, 0, 2, 4, 5]);
+    assert_eq!(arr[4], "ccc");
+}
+
+#[test]
+#[should_panic(expected = "The bucket 3 is out of range (3 buckets)")]
+fn test_partition_by_bucket_out_of_range() {
+    let mut arr = vec![1_u32, 2, 3];
+    voracious_partition_by(&mut arr, 3, |x| *x as usize);
+}
@@ -0,0 +1,143 @@
+use std::cmp::Ordering;
+use std::mem::size_of;
+
+use rand::seq::SliceRandom;
+use rand::{thread_rng, Rng};
+
+use super::super::algo::permutation::apply_permutation;
+use super::super::sorts::cached_key_sort::{
+    sort_cached_keys, CachedKey, Mode, INDIRECT_SIZE,
+};
+use super::super::{apply_permutation_in_place, RadixSort, Radixable};
+
+// The payload is derived from the key, to check that the items are moved
+// whole.
+#[derive(Copy, Clone, Debug)]
+pub(super) struct Large {
+    key: u64,
+    payload: [u64; 7],
+    tag: usize,
+}
+
+impl Large {
+    fn new(key: u64, tag: usize) -> Large {
+        let mut payload = [0; 7];
+        for (i, word) in payload.iter_mut().enumerate() {
+            *word = key.rotate_left(i as u32 * 9);
+        }
+        Large { key, payload, tag }
+    }
+}
+
+impl PartialOrd for Large {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.key.partial_cmp(&other.key)
+    }
+}
+
+impl PartialEq for Large {
+    fn eq(&self, other: &Self) -> bool { self.key == other.key }
+}
+
+impl Radixable<u64> for Large {
+    type Key = u64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.key }
+}
+
+pub(super) fn helper_random_array_large(size: usize, max: u64) -> Vec<Large> {
+    let mut rng = thread_rng();
+    (0..size).map(|tag| Large::new(rng.gen_range(0, max), tag)).collect()
+}
+
+pub(super) fn helper_check_large(sorted: &[Large], input: &[Large], stable: bool) {
+    let mut check = input.to_vec();
+    check.sort_by_key(|item| item.key);
+
+    assert_eq!(sorted.len(), check.len());
+    for (item, expected) in sorted.iter().zip(check.iter()) {
+        assert_eq!(item.key, expected.key);
+        assert_eq!(item.payload, Large::new(item.key, 0).payload);
+        if stable {
+            assert_eq!(item.tag, expected.tag);
+        }
+    }
+}
+
+#[test]
+fn test_apply_permutation_in_place() {
+    let mut rng = thread_rng();
+    for size in [0, 1, 2, 10, 1_000, 100_000].iter() {
+        let arr: Vec<u64> = (0..*size).map(|_| rng.gen()).collect();
+        let mut perm: Vec<u32> = (0..*size as u32).collect();
+        perm.as_mut_slice().shuffle(&mut rng);
+
+        let check: Vec<u64> = perm.iter().map(|&i| arr[i as usize]).collect();
+        let mut permuted = arr.to_vec();
+        apply_permutation_in_place(&mut permuted, &mut perm);
+
+        assert_eq!(permuted, check);
+        assert!(perm.iter().enumerate().all(|(i, &p)| p as usize == i));
+    }
+}
+
+// The arrays longer than u32::MAX use usize indices: the same code is checked
+// on a small array.
+#[test]
+fn test_apply_permutation_usize_indices() {
+    let mut rng = thread_rng();
+    let size = 100_000;
+    let arr: Vec<u64> = (0..size).map(|_| rng.gen_range(0, 1_000)).collect();
+    let mut keys: Vec<CachedKey<u64, usize>> = arr
+        .iter()
+        .enumerate()
+        .map(|(index, &key)| CachedKey { key, index })
+        .collect();
+    sort_cached_keys(&mut keys, Mode::Stable);
+
+    let mut perm: Vec<usize> = keys.iter().map(|cached| cached.index).collect();
+    let mut permuted = arr.to_vec();
+    apply_permutation(&mut permuted, &mut perm);
+
+    let mut check = arr.to_vec();
+    check.sort();
+    assert_eq!(permuted, check);
+    assert!(perm.iter().enumerate().all(|(i, &p)| p == i));
+}
+
+#[test]
+#[should_panic(expected = "not a permutation")]
+fn test_apply_permutation_in_place_not_a_permutation() {
+    let mut arr = vec![1, 2, 3, 4];
+    apply_permutation_in_place(&mut arr, &mut [1, 0, 3, 1]);
+}
+
+#[test]
+#[should_panic(expected = "different lengths")]
+fn test_apply_permutation_in_place_different_lengths() {
+    let mut arr = vec![1, 2, 3, 4];
+    apply_permutation_in_place(&mut arr, &mut [1, 0, 2]);
+}
+
+#[test]
+fn test_indirect_sort_large_items() {
+    assert!(size_of::<Large>() >= INDIRECT_SIZE);
+
+    for size in [0, 1, 200, 10_000, 100_000].iter() {
+        for max in [16, u64::MAX].iter() {
+            let input = helper_random_array_large(*size, *max);
+
+            let mut arr = input.to_vec();
+            arr.voracious_sort();
+            helper_check_large(&arr, &input, false);
+
+            let mut arr = input.to_vec();
+            arr.voracious_stable_sort();
+            helper_check_large(&arr, &input, true);
+
+            let mut arr = input.to_vec();
+            arr.voracious_mt_sort(4);
+            helper_check_large(&arr, &input, false);
+        }
+    }
+}
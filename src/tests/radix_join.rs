@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+
+use rand::{thread_rng, Rng};
+
+use super::super::{
+    radix_anti_join, radix_anti_join_mt, radix_join, radix_join_mt,
+    radix_left_join, radix_left_join_mt, radix_semi_join, radix_semi_join_mt,
+};
+
+// Inner, left, semi and anti joins.
+type Joins =
+    (Vec<(usize, usize)>, Vec<(usize, Option<usize>)>, Vec<usize>, Vec<usize>);
+
+// Reference joins: the output is sorted by key, then by left index and by
+// right index.
+fn reference_left_join<K: Ord + Copy>(
+    left: &[K],
+    right: &[K],
+) -> Vec<(usize, Option<usize>)> {
+    let mut matches: BTreeMap<K, Vec<usize>> = BTreeMap::new();
+    for (j, key) in right.iter().enumerate() {
+        matches.entry(*key).or_default().push(j);
+    }
+
+    let mut order: Vec<usize> = (0..left.len()).collect();
+    order.sort_by_key(|i| (left[*i], *i));
+
+    let mut output = Vec::new();
+    for i in order {
+        match matches.get(&left[i]) {
+            Some(js) => output.extend(js.iter().map(|j| (i, Some(*j)))),
+            None => output.push((i, None)),
+        }
+    }
+    output
+}
+
+fn helper_check_joins<K: Ord + Copy>(
+    left: &[K],
+    right: &[K],
+    radix_joins: Joins,
+) {
+    let left_join = reference_left_join(left, right);
+    let inner: Vec<(usize, usize)> =
+        left_join.iter().filter_map(|(i, j)| j.map(|j| (*i, j))).collect();
+    let mut semi: Vec<usize> = left_join
+        .iter()
+        .filter(|(_, j)| j.is_some())
+        .map(|(i, _)| *i)
+        .collect();
+    semi.dedup();
+    let anti: Vec<usize> = left_join
+        .iter()
+        .filter(|(_, j)| j.is_none())
+        .map(|(i, _)| *i)
+        .collect();
+
+    assert_eq!(radix_joins.0, inner);
+    assert_eq!(radix_joins.1, left_join);
+    assert_eq!(radix_joins.2, semi);
+    assert_eq!(radix_joins.3, anti);
+}
+
+#[test]
+fn test_radix_join_u64() {
+    let mut rng = thread_rng();
+    let sizes = [
+        (0, 0),
+        (0, 10),
+        (10, 0),
+        (1, 1),
+        (500, 300),
+        (50_000, 2_000),
+        (300_000, 100),
+    ];
+    for (left_size, right_size) in sizes.iter() {
+        for max in [16, 100_000, u64::MAX].iter() {
+            let left: Vec<(u64, usize)> =
+                (0..*left_size).map(|i| (rng.gen_range(0, *max), i)).collect();
+            let right: Vec<u64> =
+                (0..*right_size).map(|_| rng.gen_range(0, *max)).collect();
+            let left_keys: Vec<u64> = left.iter().map(|l| l.0).collect();
+
+            let joins = (
+                radix_join(&left, &right, |l| l.0, |r| *r),
+                radix_left_join(&left, &right, |l| l.0, |r| *r),
+                radix_semi_join(&left, &right, |l| l.0, |r| *r),
+                radix_anti_join(&left, &right, |l| l.0, |r| *r),
+            );
+            helper_check_joins(&left_keys, &right, joins);
+
+            let joins = (
+                radix_join_mt(&left, &right, |l| l.0, |r| *r, 4),
+                radix_left_join_mt(&left, &right, |l| l.0, |r| *r, 4),
+                radix_semi_join_mt(&left, &right, |l| l.0, |r| *r, 4),
+                radix_anti_join_mt(&left, &right, |l| l.0, |r| *r, 4),
+            );
+            helper_check_joins(&left_keys, &right, joins);
+        }
+    }
+}
+
+#[test]
+fn test_radix_join_key_types() {
+    let mut rng = thread_rng();
+
+    let left: Vec<i32> =
+        (0..20_000).map(|_| rng.gen_range(-500, 500)).collect();
+    let right: Vec<i32> =
+        (0..1_000).map(|_| rng.gen_range(-800, 800)).collect();
+    let joins = (
+        radix_join(&left, &right, |l| *l, |r| *r),
+        radix_left_join(&left, &right, |l| *l, |r| *r),
+        radix_semi_join(&left, &right, |l| *l, |r| *r),
+        radix_anti_join(&left, &right, |l| *l, |r| *r),
+    );
+    helper_check_joins(&left, &right, joins);
+
+    let left: Vec<u128> =
+        (0..20_000).map(|_| (rng.gen_range(0, 300) as u128) << 100).collect();
+    let right: Vec<u128> =
+        (0..1_000).map(|_| (rng.gen_range(0, 600) as u128) << 100).collect();
+    let joins = (
+        radix_join_mt(&left, &right, |l| *l, |r| *r, 2),
+        radix_left_join_mt(&left, &right, |l| *l, |r| *r, 2),
+        radix_semi_join_mt(&left, &right, |l| *l, |r| *r, 2),
+        radix_anti_join_mt(&left, &right, |l| *l, |r| *r, 2),
+    );
+    helper_check_joins(&left, &right, joins);
+
+    // All the keys are equal to zero.
+    let left = vec![0_u16; 300];
+    let right = vec![0_u16; 3];
+    let joins = (
+        radix_join(&left, &right, |l| *l, |r| *r),
+        radix_left_join(&left, &right, |l| *l, |r| *r),
+        radix_semi_join(&left, &right, |l| *l, |r| *r),
+        radix_anti_join(&left, &right, |l| *l, |r| *r),
+    );
+    helper_check_joins(&left, &right, joins);
+}
+
+#[test]
+fn test_radix_join_skewed() {
+    // The keys share their high digits, so the buckets of the first digit
+    // are too large for the cache and are partitioned again.
+    let mut rng = thread_rng();
+    let base: u64 = 1 << 40;
+    let left: Vec<u64> =
+        (0..300_000).map(|_| base + rng.gen_range(0, 1 << 20)).collect();
+    let right: Vec<u64> =
+        (0..3_000).map(|_| base + rng.gen_range(0, 1 << 20)).collect();
+    let joins = (
+        radix_join(&left, &right, |l| *l, |r| *r),
+        radix_left_join(&left, &right, |l| *l, |r| *r),
+        radix_semi_join(&left, &right, |l| *l, |r| *r),
+        radix_anti_join_mt(&left, &right, |l| *l, |r| *r, 4),
+    );
+    helper_check_joins(&left, &right, joins);
+}
+
+#[test]
+fn test_radix_join_signed_zero() {
+    // The floats are joined on their bits: -0.0 does not match 0.0.
+    let left = vec![0.0_f64, -0.0, 1.5, -0.0];
+    let right = vec![-0.0_f64, 1.5, 0.0];
+    assert_eq!(
+        radix_join(&left, &right, |l| *l, |r| *r),
+        vec![(1, 0), (3, 0), (0, 2), (2, 1)]
+    );
+    assert_eq!(
+        radix_join_mt(&left, &right, |l| *l, |r| *r, 2),
+        vec![(1, 0), (3, 0), (0, 2), (2, 1)]
+    );
+}
@@ -0,0 +1,110 @@
+use std::fmt::Debug;
+
+use super::super::sorts::utils::prefix_sums;
+use super::super::{
+    radix_partition, radix_partition_mt, radix_partition_stable, RadixKey,
+    Radixable,
+};
+
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i32::*;
+use super::super::generators::unsigned_u64::*;
+
+type Partition<'a, T> = Box<dyn Fn(&mut [T]) -> Vec<usize> + 'a>;
+
+// The digit is computed from the key, without the masks of the sorts. The
+// keys of the tested types have at most 64 bits.
+fn digit<T: Radixable<K>, K: RadixKey>(
+    item: &T,
+    bits_from: usize,
+    radix: usize,
+) -> usize {
+    let key = item.keytype_to_usize(item.into_key_type()) as u64;
+    let bits = item.type_size();
+    let digit = if bits_from + radix <= bits {
+        (key >> (bits - bits_from - radix)) & ((1 << radix) - 1)
+    } else {
+        key & ((1 << (bits - bits_from)) - 1)
+    };
+    digit as usize
+}
+
+fn helper_check_partition<T, K>(input: &[T], bits_from: usize, radix: usize)
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let mut histogram = vec![0; 1 << radix];
+    for item in input.iter() {
+        histogram[digit(item, bits_from, radix)] += 1;
+    }
+    let (expected_boundaries, _, _) = prefix_sums(&histogram);
+
+    let mut stable = input.to_vec();
+    stable.sort_by_key(|item| digit(item, bits_from, radix));
+
+    let mut arr = input.to_vec();
+    let boundaries = radix_partition_stable(&mut arr, bits_from, radix);
+    assert_eq!(boundaries, expected_boundaries);
+    assert_eq!(arr, stable);
+
+    let partitions: Vec<Partition<T>> = vec![
+        Box::new(|a| radix_partition(a, bits_from, radix)),
+        Box::new(|a| radix_partition_mt(a, bits_from, radix, 4)),
+    ];
+    for partition in partitions.iter() {
+        let mut arr = input.to_vec();
+        let boundaries = partition(&mut arr);
+        assert_eq!(boundaries, expected_boundaries);
+        for bucket in 0..(1 << radix) {
+            let items = &arr[boundaries[bucket]..boundaries[bucket + 1]];
+            assert!(items.iter().all(|i| digit(i, bits_from, radix) == bucket));
+        }
+
+        // The items are only moved.
+        let mut sorted = arr.to_vec();
+        sorted.sort_by_key(|item| item.into_key_type());
+        let mut check = input.to_vec();
+        check.sort_by_key(|item| item.into_key_type());
+        assert_eq!(sorted, check);
+    }
+}
+
+#[test]
+fn test_radix_partition() {
+    for size in [0, 1, 500, 30_000, 200_000].iter() {
+        for (bits_from, radix) in [(0, 8), (0, 1), (4, 11), (28, 8)].iter() {
+            let arr = helper_random_array_uniform_u64(*size);
+            helper_check_partition(&arr, *bits_from, *radix);
+            let arr = helper_random_array_uniform_10_9_u64(*size);
+            helper_check_partition(&arr, *bits_from, *radix);
+            let arr = helper_random_array_uniform_i32(*size);
+            helper_check_partition(&arr, *bits_from, *radix);
+            let arr = helper_random_array_uniform_f64(*size);
+            helper_check_partition(&arr, *bits_from, *radix);
+        }
+    }
+}
+
+#[test]
+fn test_radix_partition_last_bits() {
+    // The digit is made of the remaining bits.
+    let arr = helper_random_array_uniform_u64(50_000);
+    helper_check_partition(&arr, 60, 8);
+    let arr = helper_random_array_uniform_i32(50_000);
+    helper_check_partition(&arr, 31, 16);
+}
+
+#[test]
+#[should_panic(expected = "bits_from must be lower than the key size")]
+fn test_radix_partition_bits_from_too_large() {
+    let mut arr = vec![1_u32, 2, 3];
+    radix_partition(&mut arr, 32, 8);
+}
+
+#[test]
+#[should_panic(expected = "The radix must be between 1 and 16")]
+fn test_radix_partition_radix_zero() {
+    let mut arr = vec![1_u32, 2, 3];
+    radix_partition_stable(&mut arr, 0, 0);
+}
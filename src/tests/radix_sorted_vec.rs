@@ -0,0 +1,80 @@
+use rand::{thread_rng, Rng};
+
+use super::super::algo::radix_sorted_vec::RadixSortedVec;
+use super::super::types::custom::StructU32;
+
+use super::super::generators::float_32::*;
+use super::super::generators::unsigned_u64::*;
+
+#[test]
+fn test_radix_sorted_vec_extend() {
+    let mut rng = thread_rng();
+    let mut v = RadixSortedVec::new();
+    let mut check: Vec<u64> = Vec::new();
+
+    for _ in 0..50 {
+        let batch = helper_random_array_uniform_u64(rng.gen_range(0, 5_000));
+        if rng.gen::<bool>() {
+            v.extend_from_slice(&batch);
+        } else {
+            v.extend(batch.iter().copied());
+        }
+        check.extend_from_slice(&batch);
+        check.sort_unstable();
+        assert_eq!(v.as_slice(), check.as_slice());
+    }
+
+    v.insert(7);
+    check.push(7);
+    check.sort_unstable();
+    assert_eq!(v.len(), check.len());
+    assert_eq!(v.into_vec(), check);
+}
+
+#[test]
+fn test_radix_sorted_vec_queries() {
+    let v: RadixSortedVec<u32, u32> = vec![9, 1, 5, 5, 3, 7, 5].into();
+    assert_eq!(v.as_slice(), &[1, 3, 5, 5, 5, 7, 9]);
+
+    assert_eq!(v.lower_bound(&5), 2);
+    assert_eq!(v.upper_bound(&5), 5);
+    assert_eq!(v.lower_bound(&0), 0);
+    assert_eq!(v.upper_bound(&10), 7);
+    assert_eq!(v.rank(&6), 5);
+
+    assert_eq!(v.range(3..7), &[3, 5, 5, 5]);
+    assert_eq!(v.range(3..=7), &[3, 5, 5, 5, 7]);
+    assert_eq!(v.range(..5), &[1, 3]);
+    assert_eq!(v.range(6..), &[7, 9]);
+    assert_eq!(v.range(..), v.as_slice());
+    let (lo, hi) = (8, 2);
+    assert!(v.range(lo..hi).is_empty());
+    assert!(v.range(5..5).is_empty());
+}
+
+#[test]
+fn test_radix_sorted_vec_remove() {
+    let mut v: RadixSortedVec<f32, f32> =
+        helper_random_array_uniform_f32(10_000).into();
+    let removed = v.remove_range(-1.0..1.0);
+    assert!(removed.iter().all(|x| *x >= -1.0 && *x < 1.0));
+    assert!(v.iter().all(|x| *x < -1.0 || *x >= 1.0));
+    assert_eq!(v.len() + removed.len(), 10_000);
+
+    v.retain(|x| *x > 0.0);
+    assert!(v.iter().all(|x| *x > 0.0));
+    assert!(v.as_slice().windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn test_radix_sorted_vec_merge_is_stable() {
+    let mut v = RadixSortedVec::new();
+    for i in 0..1_000 {
+        v.extend_from_slice(&[StructU32 { value: (i % 5) as u32, other: i }]);
+    }
+    v.insert(StructU32 { value: 2, other: 1_000 });
+    assert!(v.as_slice().windows(2).all(|w| {
+        w[0].value < w[1].value
+            || (w[0].value == w[1].value && w[0].other < w[1].other)
+    }));
+}
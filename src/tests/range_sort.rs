@@ -0,0 +1,143 @@
+use std::fmt::Debug;
+
+use rand::{thread_rng, Rng};
+
+use super::super::{range_mt_sort, range_sort, RadixKey, RadixSort, Radixable};
+use super::permutation::{helper_check_large, helper_random_array_large};
+
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i64::*;
+use super::super::generators::unsigned_u128::*;
+use super::super::generators::unsigned_u64::*;
+
+fn helper_range_sort<T, K>(input: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let mut check = input.to_vec();
+    check.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut arr = input.to_vec();
+    range_sort(&mut arr);
+    assert_eq!(arr, check);
+
+    let mut arr = input.to_vec();
+    range_mt_sort(&mut arr, 4);
+    assert_eq!(arr, check);
+}
+
+#[test]
+fn test_range_sort() {
+    let mut rng = thread_rng();
+    for size in [0, 1, 500, 50_000, 200_000].iter() {
+        helper_range_sort(&helper_random_array_uniform_u64(*size));
+        helper_range_sort(&helper_random_array_uniform_i64(*size));
+        helper_range_sort(&helper_random_array_uniform_f64(*size));
+        helper_range_sort(&helper_random_array_uniform_u128(*size));
+
+        // Timestamps in a narrow window, far from zero.
+        let base: u64 = 1 << 40;
+        let arr: Vec<u64> =
+            (0..*size).map(|_| base + rng.gen_range(0, 1 << 20)).collect();
+        helper_range_sort(&arr);
+        let arr: Vec<i64> =
+            (0..*size).map(|_| rng.gen_range(-5_000, 5_000)).collect();
+        helper_range_sort(&arr);
+        let arr: Vec<u128> = (0..*size)
+            .map(|_| (1 << 100) + rng.gen_range(0, u64::MAX) as u128)
+            .collect();
+        helper_range_sort(&arr);
+    }
+}
+
+#[test]
+fn test_range_sort_large_items() {
+    for size in [0, 1, 500, 50_000].iter() {
+        for max in [1 << 20, u64::MAX].iter() {
+            let input = helper_random_array_large(*size, *max);
+
+            let mut arr = input.to_vec();
+            range_sort(&mut arr);
+            helper_check_large(&arr, &input, false);
+
+            let mut arr = input.to_vec();
+            range_mt_sort(&mut arr, 4);
+            helper_check_large(&arr, &input, false);
+        }
+    }
+}
+
+#[test]
+fn test_range_sort_with_range() {
+    let mut rng = thread_rng();
+    let base: u64 = 1 << 40;
+    let input: Vec<u64> =
+        (0..50_000).map(|_| base + rng.gen_range(0, 1 << 20)).collect();
+    let mut check = input.to_vec();
+    check.sort();
+
+    let mut arr = input.to_vec();
+    arr.voracious_sort_with_range(base, base + (1 << 20));
+    assert_eq!(arr, check);
+
+    let mut arr: Vec<i32> = vec![3, -2, 0, 3, -7];
+    arr.voracious_sort_with_range(-7, 3);
+    assert_eq!(arr, vec![-7, -2, 0, 3, 3]);
+}
+
+// The range straddles 2^40: `min` and `max` share no bit below 2^41, but
+// `max - min` holds on 11 bits.
+#[test]
+fn test_range_sort_straddling_range() {
+    let mut rng = thread_rng();
+    let base: u64 = 1 << 40;
+    for size in [100, 10_000, 300_000].iter() {
+        let input: Vec<u64> = (0..*size)
+            .map(|_| base - 1_000 + rng.gen_range(0, 2_000))
+            .collect();
+        helper_range_sort(&input);
+
+        let mut check = input.to_vec();
+        check.sort();
+        let mut arr = input.to_vec();
+        arr.voracious_sort_with_range(base - 1_000, base + 999);
+        assert_eq!(arr, check);
+    }
+
+    // The keys of -1 and 0 are 2^63 - 1 and 2^63.
+    let input: Vec<i64> =
+        (0..100_000).map(|_| rng.gen_range(-1_000, 1_000)).collect();
+    helper_range_sort(&input);
+}
+
+#[cfg(feature = "checked")]
+#[test]
+#[should_panic(expected = "The key of an item is out of the range")]
+fn test_range_sort_out_of_range() {
+    let mut arr: Vec<u32> = vec![5, 10, 11];
+    arr.voracious_sort_with_range(5, 10);
+}
+
+// Without the `checked` feature, the keys are not scanned: the order is
+// unspecified, but the items are kept.
+#[cfg(not(feature = "checked"))]
+#[test]
+fn test_range_sort_out_of_range_unchecked() {
+    let mut rng = thread_rng();
+    let input: Vec<u32> = (0..10_000).map(|_| rng.gen()).collect();
+    let mut arr = input.to_vec();
+    arr.voracious_sort_with_range(1 << 20, 1 << 21);
+
+    let mut check = input.to_vec();
+    check.sort();
+    arr.sort();
+    assert_eq!(arr, check);
+}
+
+#[test]
+#[should_panic(expected = "The minimum is above the maximum")]
+fn test_range_sort_bad_range() {
+    let mut arr: Vec<u32> = vec![5, 10];
+    arr.voracious_sort_with_range(10, 5);
+}
@@ -0,0 +1,120 @@
+use std::fmt::Debug;
+
+use super::super::{
+    sort_with, Algorithm, RadixKey, Radixable, SortError, SortParams,
+};
+
+use super::super::generators::float_32::*;
+use super::super::generators::float_64::*;
+use super::super::generators::option::*;
+use super::super::generators::signed_i64::*;
+use super::super::generators::signed_i8::*;
+use super::super::generators::unsigned_u128::*;
+use super::super::generators::unsigned_u16::*;
+use super::super::generators::unsigned_u32::*;
+use super::super::generators::unsigned_u64::*;
+use super::super::generators::unsigned_u8::*;
+
+// Every algorithm, with every radix: the radix values in the range of the
+// algorithm sort the array, the others are rejected.
+fn helper_registry<T, K>(input: &[T])
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let mut check = input.to_vec();
+    check.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    for algorithm in Algorithm::ALL.iter() {
+        for radix in 1..=16 {
+            let params = SortParams { radix, block_size: 2_000, thread_n: 2 };
+            let mut arr = input.to_vec();
+            let result = sort_with(&mut arr, *algorithm, params);
+
+            let valid = input.is_empty()
+                || algorithm.radix_range(input[0].type_size()).contains(&radix);
+            if valid {
+                assert_eq!(result, Ok(()), "{:?} radix {}", algorithm, radix);
+                assert_eq!(arr, check, "{:?} radix {}", algorithm, radix);
+            } else {
+                match result {
+                    Err(SortError::Radix { radix: r, .. }) => {
+                        assert_eq!(r, radix)
+                    },
+                    _ => {
+                        panic!("{:?} radix {}: {:?}", algorithm, radix, result)
+                    },
+                }
+                assert_eq!(arr, input, "{:?} radix {}", algorithm, radix);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_registry_sort_with() {
+    for size in [0, 300, 30_000].iter() {
+        helper_registry(&helper_random_array_uniform_u8(*size));
+        helper_registry(&helper_random_array_uniform_i8(*size));
+        helper_registry(&helper_random_array_uniform_u16(*size));
+        helper_registry(&helper_random_array_uniform_u32(*size));
+        helper_registry(&helper_random_array_uniform_u64(*size));
+        helper_registry(&helper_random_array_uniform_10_9_u64(*size));
+        helper_registry(&helper_random_array_uniform_u128(*size));
+        helper_registry(&helper_random_array_uniform_i64(*size));
+        helper_registry(&helper_random_array_uniform_f32(*size));
+        helper_registry(&helper_random_array_uniform_f64(*size));
+        helper_registry(&helper_random_array_uniform_option_u32(*size));
+    }
+}
+
+#[test]
+fn test_registry_properties() {
+    for algorithm in Algorithm::ALL.iter() {
+        let range = algorithm.radix_range(64);
+        assert!(*range.start() >= 1 && *range.end() <= 16);
+        assert!(range.contains(&8), "{}", algorithm.name());
+    }
+    assert!(Algorithm::MsdStable.is_stable());
+    assert!(!Algorithm::Voracious.is_stable());
+    assert!(Algorithm::Ska.is_in_place());
+    assert!(!Algorithm::Lsd.is_in_place());
+    assert!(Algorithm::Peeka.is_multithread());
+    assert_eq!(Algorithm::Msd.radix_range(8), 1..=8);
+    assert_eq!(Algorithm::Thiel.radix_range(128), 16..=16);
+}
+
+#[test]
+fn test_registry_bad_params() {
+    let mut arr: Vec<u32> = vec![3, 1, 2];
+
+    let params = SortParams { radix: 0, ..SortParams::default() };
+    let error = sort_with(&mut arr, Algorithm::Voracious, params).unwrap_err();
+    assert_eq!(
+        error,
+        SortError::Radix {
+            algorithm: Algorithm::Voracious,
+            radix: 0,
+            min: 1,
+            max: 16,
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "[sort_with] Invalid radix 0 for voracious_sort: the radix must be \
+         between 1 and 16 for this key."
+    );
+
+    let params = SortParams { thread_n: 0, ..SortParams::default() };
+    let error = sort_with(&mut arr, Algorithm::Peeka, params).unwrap_err();
+    assert_eq!(error, SortError::ThreadCount { thread_n: 0 });
+
+    let params = SortParams { block_size: 0, ..SortParams::default() };
+    let error = sort_with(&mut arr, Algorithm::Peeka, params).unwrap_err();
+    assert_eq!(error, SortError::BlockSize { block_size: 0 });
+
+    // The params of the multithread sorts are ignored by the others.
+    let params = SortParams { thread_n: 0, ..SortParams::default() };
+    assert_eq!(sort_with(&mut arr, Algorithm::Lsd, params), Ok(()));
+    assert_eq!(arr, vec![1, 2, 3]);
+}
@@ -0,0 +1,95 @@
+use std::fmt::Debug;
+use std::ops::Range;
+
+use super::super::{
+    voracious_sort_by_bits, voracious_stable_sort_by_bits, RadixKey, Radixable,
+};
+
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i32::*;
+use super::super::generators::unsigned_u128::*;
+use super::super::generators::unsigned_u64::*;
+
+// The field is computed from the key without the helpers of the sort.
+pub(super) fn field<T: Radixable<K>, K: RadixKey>(
+    item: &T,
+    bit_range: &Range<usize>,
+) -> u128 {
+    let bits = item.type_size();
+    let mut key: u128 = 0;
+    let mut value = item.into_key_type();
+    let mask = item.usize_to_keytype(0xFF);
+    for shift in (0..bits).step_by(8) {
+        let low = item.keytype_to_usize(value & mask) as u128;
+        key |= low << shift;
+        if shift + 8 < bits {
+            value = value >> item.usize_to_keytype(8);
+        }
+    }
+    let width = bit_range.end - bit_range.start;
+    let shifted = key >> (bits - bit_range.end);
+    if width == 128 {
+        shifted
+    } else {
+        shifted & ((1 << width) - 1)
+    }
+}
+
+fn helper_sort_by_bits<T, K>(input: &[T], bit_range: Range<usize>)
+where
+    T: Radixable<K> + Debug,
+    K: RadixKey,
+{
+    let mut check = input.to_vec();
+    check.sort_by_key(|item| field(item, &bit_range));
+
+    let mut arr = input.to_vec();
+    voracious_stable_sort_by_bits(&mut arr, bit_range.clone());
+    assert_eq!(arr, check);
+
+    let mut arr = input.to_vec();
+    voracious_sort_by_bits(&mut arr, bit_range.clone());
+    let fields: Vec<u128> = arr.iter().map(|x| field(x, &bit_range)).collect();
+    let check_fields: Vec<u128> =
+        check.iter().map(|x| field(x, &bit_range)).collect();
+    assert_eq!(fields, check_fields);
+    // The items are only moved.
+    arr.sort_by_key(|item| item.into_key_type());
+    check.sort_by_key(|item| item.into_key_type());
+    assert_eq!(arr, check);
+}
+
+#[test]
+fn test_sort_by_bits() {
+    for size in [0, 1, 50, 5_000, 100_000].iter() {
+        for range in [0..20, 32..48, 3..4, 7..64, 0..64, 10..10].iter() {
+            helper_sort_by_bits(
+                &helper_random_array_uniform_u64(*size),
+                range.clone(),
+            );
+            helper_sort_by_bits(
+                &helper_random_array_uniform_f64(*size),
+                range.clone(),
+            );
+        }
+        for range in [0..20, 20..32, 5..17].iter() {
+            helper_sort_by_bits(
+                &helper_random_array_uniform_i32(*size),
+                range.clone(),
+            );
+        }
+        for range in [0..128, 60..100].iter() {
+            helper_sort_by_bits(
+                &helper_random_array_uniform_u128(*size),
+                range.clone(),
+            );
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "The bit range 10..33 is not in the key (32 bits)")]
+fn test_sort_by_bits_bad_range() {
+    let mut arr = vec![1_u32, 2, 3];
+    voracious_sort_by_bits(&mut arr, 10..33);
+}
@@ -0,0 +1,132 @@
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use rand::{thread_rng, Rng};
+
+use super::super::RadixSortByKey;
+
+// Not Copy: the sort must move the String, the Vec and the Box.
+#[derive(Clone, Debug, PartialEq)]
+struct Record {
+    id: u64,
+    score: i32,
+    ratio: f64,
+    name: String,
+    values: Vec<u32>,
+    boxed: Box<usize>,
+}
+
+fn helper_random_records(size: usize, max: u64) -> Vec<Record> {
+    let mut rng = thread_rng();
+    (0..size)
+        .map(|i| {
+            let id = rng.gen_range(0, max);
+            Record {
+                id,
+                score: rng.gen_range(-50, 50),
+                ratio: rng.gen_range(-1.0, 1.0),
+                name: format!("record {}", i),
+                values: vec![id as u32; i % 4],
+                boxed: Box::new(i),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_sort_by_key_non_copy() {
+    for size in [0, 1, 200, 10_000, 100_000].iter() {
+        for max in [16, u64::MAX].iter() {
+            let input = helper_random_records(*size, *max);
+
+            // The std sort is stable, the Voracious stable sort must give the
+            // same records.
+            let mut check = input.to_vec();
+            check.sort_by_key(|record| record.id);
+
+            let mut arr = input.to_vec();
+            arr.voracious_stable_sort_by_key(|record| record.id);
+            assert_eq!(arr, check);
+
+            let mut arr = input.to_vec();
+            arr.voracious_sort_by_key(|record| record.id);
+            assert!(arr.windows(2).all(|w| w[0].id <= w[1].id));
+            arr.sort_by_key(|record| *record.boxed);
+            assert_eq!(arr, input);
+
+            let mut arr = input.to_vec();
+            arr.voracious_mt_sort_by_key(4, |record| record.id);
+            assert!(arr.windows(2).all(|w| w[0].id <= w[1].id));
+            arr.sort_by_key(|record| *record.boxed);
+            assert_eq!(arr, input);
+        }
+    }
+}
+
+#[test]
+fn test_sort_by_key_key_types() {
+    for size in [0, 1, 200, 10_000, 100_000].iter() {
+        let input = helper_random_records(*size, u64::MAX);
+
+        let mut check = input.to_vec();
+        check.sort_by_key(|record| record.score);
+        let mut arr = input.to_vec();
+        arr.voracious_stable_sort_by_key(|record| record.score);
+        assert_eq!(arr, check);
+
+        let mut check = input.to_vec();
+        check.sort_by(|a, b| a.ratio.partial_cmp(&b.ratio).unwrap());
+        let mut arr = input.to_vec();
+        arr.voracious_stable_sort_by_key(|record| record.ratio);
+        assert_eq!(arr, check);
+
+        let mut check = input.to_vec();
+        check.sort_by_key(|record| record.name.len());
+        let mut arr = input.to_vec();
+        arr.voracious_stable_sort_by_key(|record| record.name.len() as u8);
+        assert_eq!(arr, check);
+    }
+}
+
+#[test]
+fn test_sort_by_key_then() {
+    for size in [0, 1, 200, 10_000, 100_000].iter() {
+        for max in [16, u64::MAX].iter() {
+            let input = helper_random_records(*size, *max);
+
+            let mut check = input.to_vec();
+            check.sort_by(|a, b| a.id.cmp(&b.id).then(b.score.cmp(&a.score)));
+
+            // The comparator is only called on equal keys.
+            let bad_calls = Cell::new(0);
+            let mut arr = input.to_vec();
+            arr.voracious_sort_by_key_then(
+                |record| record.id,
+                |a, b| {
+                    if a.id != b.id {
+                        bad_calls.set(bad_calls.get() + 1);
+                    }
+                    b.score.cmp(&a.score)
+                },
+            );
+            assert_eq!(bad_calls.get(), 0);
+            assert_eq!(arr, check);
+        }
+    }
+}
+
+#[test]
+fn test_sort_by_key_panic_safety() {
+    let input = helper_random_records(10_000, u64::MAX);
+    let mut arr = input.to_vec();
+
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        arr.voracious_sort_by_key(|record| {
+            assert!(*record.boxed != 5_000, "key panic");
+            record.id
+        });
+    }));
+
+    assert!(result.is_err());
+    assert_eq!(arr, input);
+}
@@ -0,0 +1,375 @@
+use rand::{thread_rng, Rng};
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::net::Ipv4Addr;
+use std::num::NonZeroI32;
+use std::time::Duration;
+
+use super::super::sorts::lsd_stable_sort::lsd_stable_radixsort;
+use super::super::sorts::msd_stable_sort::msd_stable_radixsort;
+use super::super::types::custom::{StructF32, StructI16, StructU64};
+use super::super::{NoneLast, RadixKey, RadixSort, Radixable, BF16, F16};
+
+use super::super::generators::boolean::*;
+use super::super::generators::char::*;
+use super::super::generators::custom::*;
+use super::super::generators::float_32::*;
+use super::super::generators::float_64::*;
+use super::super::generators::half::*;
+use super::super::generators::option::*;
+use super::super::generators::signed_i128::*;
+use super::super::generators::signed_i16::*;
+use super::super::generators::signed_i32::*;
+use super::super::generators::signed_i64::*;
+use super::super::generators::signed_i8::*;
+use super::super::generators::std_types::*;
+use super::super::generators::unsigned_u128::*;
+use super::super::generators::unsigned_u16::*;
+use super::super::generators::unsigned_u32::*;
+use super::super::generators::unsigned_u64::*;
+use super::super::generators::unsigned_u8::*;
+
+// Sizes around the thresholds of the stable dispatch.
+const SIZES: [usize; 20] = [
+    0, 1, 2, 50, 127, 128, 129, 200, 201, 300, 301, 349, 350, 500, 501, 800,
+    8_000, 8_001, 100_000, 100_001,
+];
+
+// The tag is the position of the item in the input array, it is not part of
+// the key.
+#[derive(Copy, Clone, Debug)]
+struct Tagged<T> {
+    value: T,
+    tag: usize,
+}
+
+impl<T: PartialOrd> PartialOrd for Tagged<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: PartialEq> PartialEq for Tagged<T> {
+    fn eq(&self, other: &Self) -> bool { self.value == other.value }
+}
+
+impl Radixable<bool> for Tagged<bool> {
+    type Key = bool;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<char> for Tagged<char> {
+    type Key = char;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<f32> for Tagged<f32> {
+    type Key = f32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<f64> for Tagged<f64> {
+    type Key = f64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<F16> for Tagged<F16> {
+    type Key = F16;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<BF16> for Tagged<BF16> {
+    type Key = BF16;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<u8> for Tagged<u8> {
+    type Key = u8;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<u16> for Tagged<u16> {
+    type Key = u16;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<u32> for Tagged<u32> {
+    type Key = u32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<u64> for Tagged<u64> {
+    type Key = u64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<u128> for Tagged<u128> {
+    type Key = u128;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<usize> for Tagged<usize> {
+    type Key = usize;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<i8> for Tagged<i8> {
+    type Key = i8;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<i16> for Tagged<i16> {
+    type Key = i16;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<i32> for Tagged<i32> {
+    type Key = i32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<i64> for Tagged<i64> {
+    type Key = i64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<i128> for Tagged<i128> {
+    type Key = i128;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<isize> for Tagged<isize> {
+    type Key = isize;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<Option<u32>> for Tagged<Option<u32>> {
+    type Key = Option<u32>;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<NoneLast<u16>> for Tagged<NoneLast<u16>> {
+    type Key = NoneLast<u16>;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<Duration> for Tagged<Duration> {
+    type Key = Duration;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<Ipv4Addr> for Tagged<Ipv4Addr> {
+    type Key = Ipv4Addr;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<NonZeroI32> for Tagged<NonZeroI32> {
+    type Key = NonZeroI32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<Reverse<u32>> for Tagged<Reverse<u32>> {
+    type Key = Reverse<u32>;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+impl Radixable<f32> for Tagged<StructF32> {
+    type Key = f32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value.value }
+}
+
+impl Radixable<u64> for Tagged<StructU64> {
+    type Key = u64;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value.value }
+}
+
+impl Radixable<i16> for Tagged<StructI16> {
+    type Key = i16;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value.value }
+}
+
+// Many duplicates, and long descending runs with plateaus, which are
+// reversed by the Verge sort pre-processing.
+fn patterns<T: Copy + PartialOrd>(
+    pool: &dyn Fn(usize) -> Vec<T>,
+    size: usize,
+) -> Vec<(Vec<T>, &'static str)> {
+    let mut rng = thread_rng();
+    let mut values = pool(size / 8 + 1);
+    let uniform =
+        (0..size).map(|_| values[rng.gen_range(0, values.len())]).collect();
+
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let repeat = size / values.len() + 1;
+    let ascending: Vec<T> = values
+        .iter()
+        .flat_map(|v| vec![*v; repeat])
+        .take(size)
+        .collect();
+    let mut descending = ascending.clone();
+    descending.reverse();
+    let mut asc_desc: Vec<T> = ascending[..size / 2].to_vec();
+    asc_desc.extend_from_slice(&descending[size / 2..]);
+    let equal = vec![values[0]; size];
+
+    vec![
+        (uniform, "uniform"),
+        (ascending, "ascending"),
+        (descending, "descending"),
+        (asc_desc, "asc_desc"),
+        (equal, "equal"),
+    ]
+}
+
+fn check_stable<T, K>(input: &[Tagged<T>], output: &[Tagged<T>], name: &str)
+where
+    Tagged<T>: Radixable<K>,
+    K: RadixKey,
+    T: Debug,
+{
+    assert_eq!(input.len(), output.len());
+
+    let mut last_tags = BTreeMap::new();
+    for (i, item) in output.iter().enumerate() {
+        if i > 0 {
+            assert!(output[i - 1] <= *item, "{}: not sorted at {}", name, i);
+        }
+        let key = item.into_key_type();
+        assert!(key == input[item.tag].into_key_type());
+        if let Some(last_tag) = last_tags.insert(key, item.tag) {
+            assert!(
+                last_tag < item.tag,
+                "{}: not stable at {}: {:?}",
+                name,
+                i,
+                item
+            );
+        }
+    }
+}
+
+fn helper_stable<T, K>(pool: &dyn Fn(usize) -> Vec<T>, type_name: &str)
+where
+    T: Copy + PartialOrd + Debug,
+    Tagged<T>: Radixable<K>,
+    K: RadixKey,
+{
+    for size in SIZES.iter() {
+        for (values, pattern) in patterns(pool, *size).into_iter() {
+            let input: Vec<Tagged<T>> = values
+                .into_iter()
+                .enumerate()
+                .map(|(tag, value)| Tagged { value, tag })
+                .collect();
+            let name = format!("{} {} {}", type_name, pattern, size);
+
+            let mut output = input.clone();
+            output.as_mut_slice().voracious_stable_sort();
+            check_stable(&input, &output, &name);
+
+            let mut output = input.clone();
+            output.voracious_stable_sort();
+            check_stable(&input, &output, &name);
+
+            let mut output = input.clone();
+            lsd_stable_radixsort(&mut output, 8);
+            check_stable(&input, &output, &name);
+
+            let mut output = input.clone();
+            msd_stable_radixsort(&mut output, 8);
+            check_stable(&input, &output, &name);
+        }
+    }
+}
+
+#[test]
+fn test_stable_sort_bool_char() {
+    helper_stable(&helper_random_array_bool_unif, "bool");
+    helper_stable(&helper_random_array_uniform_char, "char");
+}
+
+#[test]
+fn test_stable_sort_floats() {
+    helper_stable(&helper_random_array_uniform_f32, "f32");
+    helper_stable(&helper_random_array_uniform_f64, "f64");
+    helper_stable(&helper_random_array_uniform_f16, "f16");
+    helper_stable(&helper_random_array_uniform_bf16, "bf16");
+}
+
+#[test]
+fn test_stable_sort_unsigned() {
+    helper_stable(&helper_random_array_uniform_u8, "u8");
+    helper_stable(&helper_random_array_uniform_u16, "u16");
+    helper_stable(&helper_random_array_uniform_u32, "u32");
+    helper_stable(&helper_random_array_uniform_u64, "u64");
+    helper_stable(&helper_random_array_uniform_u128, "u128");
+    helper_stable(
+        &|size| {
+            let arr = helper_random_array_uniform_u64(size);
+            arr.into_iter().map(|v| v as usize).collect()
+        },
+        "usize",
+    );
+}
+
+#[test]
+fn test_stable_sort_signed() {
+    helper_stable(&helper_random_array_uniform_i8, "i8");
+    helper_stable(&helper_random_array_uniform_i16, "i16");
+    helper_stable(&helper_random_array_uniform_i32, "i32");
+    helper_stable(&helper_random_array_uniform_i64, "i64");
+    helper_stable(&helper_random_array_uniform_i128, "i128");
+    helper_stable(
+        &|size| {
+            let arr = helper_random_array_uniform_i64(size);
+            arr.into_iter().map(|v| v as isize).collect()
+        },
+        "isize",
+    );
+}
+
+#[test]
+fn test_stable_sort_std_types() {
+    helper_stable(&helper_random_array_uniform_option_u32, "option_u32");
+    helper_stable(&helper_random_array_uniform_nonelast_u16, "nonelast_u16");
+    helper_stable(&helper_random_array_uniform_duration, "duration");
+    helper_stable(&helper_random_array_uniform_ipv4, "ipv4");
+    helper_stable(&helper_random_array_uniform_nonzero_i32, "nonzero_i32");
+    helper_stable(&helper_random_array_uniform_reverse_u32, "reverse_u32");
+}
+
+#[test]
+fn test_stable_sort_structs() {
+    helper_stable(&helper_random_array_uniform_structf32, "struct_f32");
+    helper_stable(&helper_random_array_uniform_structu64, "struct_u64");
+    helper_stable(&helper_random_array_uniform_structi16, "struct_i16");
+}
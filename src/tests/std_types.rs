@@ -0,0 +1,93 @@
+use std::cmp::Reverse;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::num::{NonZeroI8, NonZeroU64, Wrapping};
+use std::time::{Duration, UNIX_EPOCH};
+
+use super::super::{RadixKey, RadixSort, Radixable};
+
+use super::super::generators::std_types::*;
+
+fn helper_sort_ord<T, K>(
+    sort: &dyn Fn(&mut Vec<T>),
+    generators: Vec<(&dyn Fn(usize) -> Vec<T>, &'static str)>,
+    array_size: usize,
+) where
+    T: Radixable<K> + Ord + std::fmt::Debug,
+    K: RadixKey,
+{
+    generators.iter().for_each(|(generator, _gen_name)| {
+        let mut array = generator(array_size);
+        let mut check = array.to_vec();
+        sort(&mut array);
+        check.sort();
+        assert_eq!(check, array);
+    });
+}
+
+#[test]
+fn test_std_types_into_keytype() {
+    assert_eq!(Duration::new(1, 2).into_keytype(), (1 << 32) | 2);
+    assert_eq!(RadixKey::type_size(&Duration::new(0, 0)), 96);
+    assert_eq!(UNIX_EPOCH.into_keytype(), 1 << 96);
+    assert!(
+        (UNIX_EPOCH - Duration::new(0, 1)).into_keytype() < 1 << 96
+    );
+    assert_eq!(Ipv4Addr::new(1, 2, 3, 4).into_keytype(), 0x0102_0304);
+    assert_eq!(Ipv6Addr::LOCALHOST.into_keytype(), 1);
+    assert_eq!(NonZeroU64::new(7).unwrap().into_keytype(), 7);
+    assert_eq!(NonZeroI8::new(-1).unwrap().into_keytype(), 0x7F);
+    assert_eq!(Wrapping(-1i8).into_keytype(), 0x7F);
+    assert_eq!(Reverse(0u8).into_keytype(), 0xFF);
+    assert_eq!(Reverse(true).into_keytype(), 0xFE);
+    assert_eq!(Reverse(-1i8).into_keytype(), 0x80);
+    // Only the 96 meaningful bits are inverted.
+    assert_eq!(Reverse(Duration::new(0, 0)).into_keytype(), (1 << 96) - 1);
+}
+
+#[test]
+fn test_std_types_trait_voracious() {
+    for size in [0, 1, 200, 5_000, 50_000].iter() {
+        helper_sort_ord(&|a| a.voracious_sort(), generators_duration(), *size);
+        helper_sort_ord(&|a| a.voracious_sort(), generators_systemtime(), *size);
+        helper_sort_ord(&|a| a.voracious_sort(), generators_ipv4(), *size);
+        helper_sort_ord(&|a| a.voracious_sort(), generators_ipv6(), *size);
+        helper_sort_ord(&|a| a.voracious_sort(), generators_nonzero_u64(), *size);
+        helper_sort_ord(&|a| a.voracious_sort(), generators_nonzero_i32(), *size);
+        helper_sort_ord(&|a| a.voracious_sort(), generators_wrapping_i16(), *size);
+        helper_sort_ord(&|a| a.voracious_sort(), generators_reverse_u32(), *size);
+        helper_sort_ord(&|a| a.voracious_sort(), generators_reverse_i64(), *size);
+        helper_sort_ord(&|a| a.voracious_sort(), generators_reverse_duration(), *size);
+    }
+}
+
+#[test]
+fn test_std_types_trait_voracious_stable() {
+    for size in [0, 1, 200, 5_000, 50_000].iter() {
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_duration(), *size);
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_systemtime(), *size);
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_ipv4(), *size);
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_ipv6(), *size);
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_nonzero_u64(), *size);
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_nonzero_i32(), *size);
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_wrapping_i16(), *size);
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_reverse_u32(), *size);
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_reverse_i64(), *size);
+        helper_sort_ord(&|a| a.voracious_stable_sort(), generators_reverse_duration(), *size);
+    }
+}
+
+#[test]
+fn test_std_types_trait_voracious_mt_sort() {
+    for size in [0, 1, 10_000, 100_000].iter() {
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_duration(), *size);
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_systemtime(), *size);
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_ipv4(), *size);
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_ipv6(), *size);
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_nonzero_u64(), *size);
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_nonzero_i32(), *size);
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_wrapping_i16(), *size);
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_reverse_u32(), *size);
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_reverse_i64(), *size);
+        helper_sort_ord(&|a| a.voracious_mt_sort(4), generators_reverse_duration(), *size);
+    }
+}
@@ -0,0 +1,69 @@
+use rand::{thread_rng, Rng};
+
+use super::super::algo::streaming_sorter::StreamingSorter;
+use super::super::types::custom::StructU32;
+
+use super::super::generators::float_64::*;
+use super::super::generators::unsigned_u32::*;
+
+#[test]
+fn test_streaming_sorter_empty() {
+    let mut sorter: StreamingSorter<u32> = StreamingSorter::new();
+    sorter.push(&[]);
+    assert!(sorter.is_empty());
+    assert_eq!(sorter.snapshot(), Vec::<u32>::new());
+    assert_eq!(sorter.finish(), Vec::<u32>::new());
+}
+
+#[test]
+fn test_streaming_sorter_u32() {
+    let mut rng = thread_rng();
+    let mut sorter = StreamingSorter::new();
+    let mut check = Vec::new();
+
+    for i in 0..300 {
+        let batch = helper_random_array_uniform_u32(rng.gen_range(0, 2_000));
+        sorter.push(&batch);
+        check.extend_from_slice(&batch);
+        let log2 = 64 - (sorter.len() as u64).leading_zeros() as usize;
+        assert!(sorter.run_count() <= log2);
+
+        if i % 50 == 0 {
+            check.sort_unstable();
+            assert_eq!(sorter.snapshot(), check);
+        }
+    }
+
+    check.sort_unstable();
+    assert_eq!(sorter.len(), check.len());
+    assert_eq!(sorter.finish(), check);
+}
+
+#[test]
+fn test_streaming_sorter_f64() {
+    let mut sorter = StreamingSorter::new();
+    let mut check = Vec::new();
+
+    for size in [1, 10, 1_000, 50_000, 3, 200, 7_000].iter() {
+        let batch = helper_random_array_uniform_f64(*size);
+        sorter.push(&batch);
+        check.extend_from_slice(&batch);
+    }
+
+    check.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+    assert_eq!(sorter.finish(), check);
+}
+
+#[test]
+fn test_streaming_sorter_merge_is_stable() {
+    // One item per batch: the batch sort can not break the stability.
+    let mut sorter = StreamingSorter::new();
+    for i in 0..5_000 {
+        sorter.push(&[StructU32 { value: (i % 7) as u32, other: i }]);
+    }
+    let sorted = sorter.finish();
+    assert!(sorted.windows(2).all(|w| {
+        w[0].value < w[1].value
+            || (w[0].value == w[1].value && w[0].other < w[1].other)
+    }));
+}
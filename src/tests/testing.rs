@@ -0,0 +1,116 @@
+use std::cmp::Ordering;
+
+use rand::{thread_rng, Rng};
+
+use super::super::testing::{check_counting_sort, check_radixable};
+use super::super::Radixable;
+
+use super::super::generators::custom::*;
+use super::super::generators::float_64::*;
+use super::super::generators::signed_i16::*;
+use super::super::generators::signed_i64::*;
+use super::super::generators::unsigned_u32::*;
+use super::super::generators::unsigned_u8::*;
+
+// Debug shows the id too, so the stability is observable.
+#[derive(Copy, Clone, Debug)]
+struct Tagged {
+    value: f32,
+    #[allow(dead_code)]
+    id: usize,
+}
+
+impl PartialOrd for Tagged {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl PartialEq for Tagged {
+    fn eq(&self, other: &Self) -> bool { self.value == other.value }
+}
+
+impl Radixable<f32> for Tagged {
+    type Key = f32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+// The type size is too small for the keys.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+struct Truncated {
+    value: u32,
+}
+
+impl Radixable<u32> for Truncated {
+    type Key = u32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+    #[inline]
+    fn type_size(&self) -> usize { 16 }
+}
+
+// PartialOrd is the opposite of the key order.
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Reversed {
+    value: u32,
+}
+
+impl PartialOrd for Reversed {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.value.partial_cmp(&self.value)
+    }
+}
+
+impl Radixable<u32> for Reversed {
+    type Key = u32;
+    #[inline]
+    fn key(&self) -> Self::Key { self.value }
+}
+
+#[test]
+fn test_check_radixable_primitives() {
+    check_radixable(&Vec::<u32>::new());
+    check_radixable(&helper_random_array_uniform_u32(50));
+    check_radixable(&helper_random_array_uniform_u32(30_000));
+    check_radixable(&helper_random_array_uniform_i64(5_000));
+    check_radixable(&helper_random_array_uniform_f64(5_000));
+    check_radixable(&helper_random_array_uniform_u8(1_000));
+}
+
+#[test]
+fn test_check_radixable_custom() {
+    check_radixable(&helper_random_array_uniform_structu64(5_000));
+    check_radixable(&helper_random_array_uniform_structi16(5_000));
+
+    let mut rng = thread_rng();
+    let samples: Vec<Tagged> = (0..5_000)
+        .map(|id| Tagged { value: rng.gen_range(-50, 50) as f32, id })
+        .collect();
+    check_radixable(&samples);
+}
+
+#[test]
+fn test_check_counting_sort() {
+    check_counting_sort(&helper_random_array_uniform_u8(1_000));
+    check_counting_sort(&helper_random_array_uniform_i16(1_000));
+    check_counting_sort(&[true, false, false, true]);
+}
+
+#[test]
+#[should_panic(expected = "type_size")]
+fn test_check_radixable_wrong_type_size() {
+    let samples: Vec<Truncated> = helper_random_array_uniform_u32(100)
+        .into_iter()
+        .map(|value| Truncated { value: value | 0x1_0000 })
+        .collect();
+    check_radixable(&samples);
+}
+
+#[test]
+#[should_panic(expected = "key/PartialOrd")]
+fn test_check_radixable_inconsistent_order() {
+    let samples: Vec<Reversed> =
+        (0..100).map(|value| Reversed { value }).collect();
+    check_radixable(&samples);
+}
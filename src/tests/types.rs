@@ -1,5 +1,11 @@
+use std::cmp::{Ordering, Reverse};
+
+use rand::{thread_rng, Rng};
+
 use super::super::sorts::utils::Params;
-use super::super::Radixable;
+use super::super::{
+    CompositeKey, KeySpec, Nulls, RadixKey, RadixSort, Radixable,
+};
 
 #[test]
 fn test_types_compute_offset() {
@@ -199,3 +205,156 @@ fn test_types_get_mask_and_shift() {
     assert_eq!(mask, 0b0000_1111_1110_0000_0000_0000_0000_0000u32);
     assert_eq!(shift, 21);
 }
+
+#[test]
+fn test_types_composite_key() {
+    let key = CompositeKey(3u8, -1i16);
+    assert_eq!(RadixKey::type_size(&key), 24);
+    assert_eq!(key.into_keytype(), 0x03_7FFF);
+
+    let key = CompositeKey(true, CompositeKey(Reverse(2u32), 1u64));
+    assert_eq!(RadixKey::type_size(&key), 104);
+    assert_eq!(
+        key.into_keytype(),
+        (1 << 96) | (0xFFFF_FFFD << 64) | 1
+    );
+
+    let mut array = vec![
+        CompositeKey(2u8, 'b'),
+        CompositeKey(1u8, 'z'),
+        CompositeKey(2u8, 'a'),
+        CompositeKey(1u8, 'c'),
+    ];
+    array.voracious_sort();
+    assert_eq!(array, vec![
+        CompositeKey(1u8, 'c'),
+        CompositeKey(1u8, 'z'),
+        CompositeKey(2u8, 'a'),
+        CompositeKey(2u8, 'b'),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "A composite key can not exceed 128 bits.")]
+fn test_types_composite_key_too_wide() {
+    CompositeKey(1u64, CompositeKey(2u32, 3u64)).into_keytype();
+}
+
+#[test]
+fn test_types_key_spec() {
+    let key = KeySpec::new().asc(3u8).desc(-1i16);
+    assert_eq!(key.bits(), 24);
+    assert_eq!(key.to_u128(), 0x03_8000);
+
+    let key = KeySpec::new().asc(1u64).asc(2u64);
+    assert_eq!(key.to_u128(), (1 << 64) | 2);
+    assert_eq!(KeySpec::new().desc(0u128).to_u128(), u128::MAX);
+
+    // The None values are placed by `nulls`, whatever the direction is.
+    type Build = fn(KeySpec, Option<u8>, Nulls) -> KeySpec;
+    let builds: [Build; 2] = [KeySpec::asc_option, KeySpec::desc_option];
+    for build in builds.iter() {
+        let key = |value, nulls| build(KeySpec::new(), value, nulls);
+        for value in [Some(0), Some(255)].iter() {
+            assert!(key(None, Nulls::First) < key(*value, Nulls::First));
+            assert!(key(None, Nulls::Last) > key(*value, Nulls::Last));
+        }
+    }
+    let asc = KeySpec::new().asc_option(Some(1u8), Nulls::First);
+    let desc = KeySpec::new().desc_option(Some(1u8), Nulls::First);
+    assert!(asc < KeySpec::new().asc_option(Some(2u8), Nulls::First));
+    assert!(desc > KeySpec::new().desc_option(Some(2u8), Nulls::First));
+}
+
+#[test]
+#[should_panic(expected = "The fields can not exceed 128 bits")]
+fn test_types_key_spec_too_large() {
+    KeySpec::new().asc(1u64).asc(1u32).asc(1u64);
+}
+
+// The id is not a part of the key, it checks the stability.
+#[derive(Copy, Clone, Debug)]
+struct Player {
+    region: u8,
+    score: f32,
+    rank: Option<u16>,
+    id: u32,
+}
+
+impl Player {
+    fn spec(&self) -> KeySpec {
+        KeySpec::new()
+            .asc(self.region)
+            .desc(self.score)
+            .desc_option(self.rank, Nulls::First)
+    }
+}
+
+impl PartialOrd for Player {
+    fn partial_cmp(&self, other: &Player) -> Option<Ordering> {
+        self.spec().partial_cmp(&other.spec())
+    }
+}
+
+impl PartialEq for Player {
+    fn eq(&self, other: &Player) -> bool { self.spec() == other.spec() }
+}
+
+impl Radixable<KeySpec> for Player {
+    type Key = KeySpec;
+    #[inline]
+    fn key(&self) -> KeySpec { self.spec() }
+}
+
+fn nulls_first_desc(a: Option<u16>, b: Option<u16>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => b.cmp(&a),
+    }
+}
+
+#[test]
+fn test_types_key_spec_sort() {
+    let mut rng = thread_rng();
+    for size in [0, 100, 10_000, 300_000].iter() {
+        let input: Vec<Player> = (0..*size)
+            .map(|id| Player {
+                region: rng.gen_range(0, 4),
+                score: rng.gen_range(-8, 8) as f32 / 2.0,
+                rank: if rng.gen_bool(0.1) {
+                    None
+                } else {
+                    Some(rng.gen_range(0, 50))
+                },
+                id,
+            })
+            .collect();
+
+        let mut check = input.to_vec();
+        check.sort_by(|a, b| {
+            a.region
+                .cmp(&b.region)
+                .then(b.score.partial_cmp(&a.score).unwrap())
+                .then(nulls_first_desc(a.rank, b.rank))
+        });
+
+        let mut arr = input.to_vec();
+        arr.voracious_stable_sort();
+        let ids: Vec<_> = arr.iter().map(|p| p.id).collect();
+        let check_ids: Vec<_> = check.iter().map(|p| p.id).collect();
+        assert_eq!(ids, check_ids);
+
+        let mut arr = input.to_vec();
+        arr.voracious_sort();
+        let keys: Vec<_> = arr.iter().map(|p| p.spec()).collect();
+        let check_keys: Vec<_> = check.iter().map(|p| p.spec()).collect();
+        assert_eq!(keys, check_keys);
+
+        let mut arr = input.to_vec();
+        arr.voracious_mt_sort(4);
+        let keys: Vec<_> = arr.iter().map(|p| p.spec()).collect();
+        assert_eq!(keys, check_keys);
+    }
+}
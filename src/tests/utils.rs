@@ -49,28 +49,28 @@ fn test_utils_only_one_bucket_filled() {
 
 #[test]
 fn test_utils_offset_from_bits() {
-    let mut arr: Vec<u32> = vec![0];
+    let arr: Vec<u32> = vec![0];
     let max: u32 = 0b0000_0111_0000_0000_0000_0000_0000_0000;
     let radix = 8;
     let bits = 32;
     let zero: u32 = 0;
     let one: u32 = 1;
     let (offset, raw_offset) =
-        offset_from_bits(&mut arr, max, radix, bits, zero, one);
+        offset_from_bits(&arr, max, radix, bits, zero, one);
     assert_eq!(offset, 0);
     assert_eq!(raw_offset, 5);
 }
 
 #[test]
 fn test_utils_compute_offset() {
-    let mut arr: Vec<u32> = vec![0b0000_0111_0000_0000_0000_0000_0000_0000];
-    let (offset, raw_offset) = compute_offset(&mut arr, 8);
+    let arr: Vec<u32> = vec![0b0000_0111_0000_0000_0000_0000_0000_0000];
+    let (offset, raw_offset) = compute_offset(&arr, 8);
     assert_eq!(offset, 0);
     assert_eq!(raw_offset, 5);
 
-    let mut arr: Vec<char> = vec!['a'];
+    let arr: Vec<char> = vec!['a'];
     // 'a': 0b0000_0000_0000_0000_0000_0000_0110_0001
-    let (offset, raw_offset) = compute_offset(&mut arr, 8);
+    let (offset, raw_offset) = compute_offset(&arr, 8);
     assert_eq!(offset, 24);
     assert_eq!(raw_offset, 25);
 }
@@ -101,12 +101,12 @@ fn test_utils_get_empty_histograms() {
 
 #[test]
 fn test_utils_get_histogram() {
-    let mut v: Vec<u64> =
+    let v: Vec<u64> =
         vec![0, 1, 2, 3, 1, 1, 2, 1, 3, 0, 1, 2, 1, 0, 3, 1, 2, 3, 1, 0, 1, 0];
-    let mut arr = v.as_mut_slice();
+    let arr = v.as_slice();
     let p = Params::new(0, 2, 62, 1); // level, radix, offset, max_level
     let (mask, shift) = arr[0].get_mask_and_shift(&p);
-    let h = get_histogram(&mut arr, &p, mask, shift);
+    let h = get_histogram(arr, &p, mask, shift);
 
     let check = vec![5, 9, 4, 4];
 
@@ -0,0 +1,59 @@
+use super::super::algo::checked::{check, CheckError};
+use super::super::{RadixKey, Radixable};
+
+/// # Checked sorts
+///
+/// Same sorts as the `RadixSort` trait, the output is verified after the
+/// sort: the `Radixable` key and `PartialOrd` must agree on a sample of
+/// pairs, and the output must be sorted. The first offending pair is
+/// reported in the `CheckError`.
+///
+/// The sorts do not go through the verification of the `checked` feature,
+/// which panics: the error is always returned.
+pub trait RadixSortChecked<T: Radixable<K>, K: RadixKey> {
+    fn voracious_sort_checked(&mut self) -> Result<(), CheckError<T>>;
+    fn voracious_stable_sort_checked(&mut self) -> Result<(), CheckError<T>>;
+    fn voracious_mt_sort_checked(
+        &mut self,
+        thread_n: usize,
+    ) -> Result<(), CheckError<T>>;
+}
+
+impl<T: Radixable<K>, K: RadixKey> RadixSortChecked<T, K> for [T] {
+    fn voracious_sort_checked(&mut self) -> Result<(), CheckError<T>> {
+        if let Some(&dummy) = self.first() {
+            dummy.voracious_sort(self);
+        }
+        check(self)
+    }
+    fn voracious_stable_sort_checked(&mut self) -> Result<(), CheckError<T>> {
+        if let Some(&dummy) = self.first() {
+            dummy.voracious_stable_sort(self);
+        }
+        check(self)
+    }
+    fn voracious_mt_sort_checked(
+        &mut self,
+        thread_n: usize,
+    ) -> Result<(), CheckError<T>> {
+        if let Some(&dummy) = self.first() {
+            dummy.voracious_mt_sort(self, thread_n);
+        }
+        check(self)
+    }
+}
+
+impl<T: Radixable<K>, K: RadixKey> RadixSortChecked<T, K> for Vec<T> {
+    fn voracious_sort_checked(&mut self) -> Result<(), CheckError<T>> {
+        self.as_mut_slice().voracious_sort_checked()
+    }
+    fn voracious_stable_sort_checked(&mut self) -> Result<(), CheckError<T>> {
+        self.as_mut_slice().voracious_stable_sort_checked()
+    }
+    fn voracious_mt_sort_checked(
+        &mut self,
+        thread_n: usize,
+    ) -> Result<(), CheckError<T>> {
+        self.as_mut_slice().voracious_mt_sort_checked(thread_n)
+    }
+}
@@ -0,0 +1,38 @@
+use rayon::iter::ParallelIterator;
+
+use super::super::sorts::collect_sort::{collect_sorted, par_collect_sorted};
+use super::super::{RadixKey, Radixable};
+
+pub trait VoraciousSorted: Iterator + Sized {
+    fn voracious_sorted<K>(self) -> Vec<Self::Item>
+    where
+        Self::Item: Radixable<K>,
+        K: RadixKey;
+}
+
+impl<I: Iterator> VoraciousSorted for I {
+    fn voracious_sorted<K>(self) -> Vec<Self::Item>
+    where
+        Self::Item: Radixable<K>,
+        K: RadixKey,
+    {
+        collect_sorted(self)
+    }
+}
+
+pub trait ParVoraciousSorted: ParallelIterator {
+    fn par_collect_voracious_sorted<K>(self, thread_n: usize) -> Vec<Self::Item>
+    where
+        Self::Item: Radixable<K>,
+        K: RadixKey;
+}
+
+impl<I: ParallelIterator> ParVoraciousSorted for I {
+    fn par_collect_voracious_sorted<K>(self, thread_n: usize) -> Vec<Self::Item>
+    where
+        Self::Item: Radixable<K>,
+        K: RadixKey,
+    {
+        par_collect_sorted(self, thread_n)
+    }
+}
@@ -1,3 +1,14 @@
+use std::cmp::{Ordering, Reverse};
+use std::marker::PhantomData;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize,
+    NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    Wrapping,
+};
+use std::ops::Not;
+use std::time::{Duration, SystemTime};
+
 use rayon::slice::ParallelSliceMut;
 
 use super::super::sorts::dlsd_sort::dlsd_radixsort;
@@ -8,7 +19,11 @@ use super::super::sorts::msd_stable_sort::msd_stable_radixsort;
 use super::super::sorts::peeka_sort::peeka_sort;
 use super::super::sorts::rollercoaster_sort::rollercoaster_sort;
 use super::super::sorts::voracious_sort::voracious_sort;
-use super::super::{RadixKey, Radixable};
+use super::super::traits::radix_key::WidenKey;
+use super::super::types::key_spec::KeySpec;
+use super::super::{
+    CompositeKey, NoneLast, RadixKey, Radixable, BF16, F16,
+};
 
 pub trait Dispatcher<T: Radixable<K>, K: RadixKey> {
     fn voracious_sort(&self, arr: &mut [T]);
@@ -120,7 +135,7 @@ impl<T: Radixable<f64>> Dispatcher<T, f64> for f64 {
     }
     fn voracious_stable_sort(&self, arr: &mut [T]) {
         if arr.len() < 350 {
-            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
         } else if arr.len() < 100_000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() < 3_000_000 {
@@ -161,7 +176,7 @@ impl<T: Radixable<i8>> Dispatcher<T, i8> for i8 {
         if arr.len() <= 500 {
             msd_stable_radixsort(arr, 8);
         } else {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
@@ -186,7 +201,7 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
         if arr.len() <= 500 {
             msd_stable_radixsort(arr, 8);
         } else {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
@@ -210,7 +225,7 @@ impl<T: Radixable<i16>> Dispatcher<T, i16> for i16 {
         if arr.len() <= 200 {
             arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
         } else {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
@@ -235,7 +250,7 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
         if arr.len() <= 200 {
             arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
         } else {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
@@ -249,7 +264,9 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
 
 impl<T: Radixable<i32>> Dispatcher<T, i32> for i32 {
     fn voracious_sort(&self, arr: &mut [T]) { lsd_radixsort(arr, 8); }
-    fn voracious_stable_sort(&self, arr: &mut [T]) { lsd_radixsort(arr, 8); }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        lsd_stable_radixsort(arr, 8);
+    }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
             arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
@@ -262,7 +279,9 @@ impl<T: Radixable<i32>> Dispatcher<T, i32> for i32 {
 #[cfg(target_pointer_width = "32")]
 impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
     fn voracious_sort(&self, arr: &mut [T]) { lsd_radixsort(arr, 8); }
-    fn voracious_stable_sort(&self, arr: &mut [T]) { lsd_radixsort(arr, 8); }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        lsd_stable_radixsort(arr, 8);
+    }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
             arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
@@ -290,7 +309,7 @@ impl<T: Radixable<i64>> Dispatcher<T, i64> for i64 {
         } else if arr.len() <= 8000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         } else {
             msd_stable_radixsort(arr, 8);
         }
@@ -323,7 +342,7 @@ impl<T: Radixable<isize>> Dispatcher<T, isize> for isize {
         } else if arr.len() <= 8000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         } else {
             msd_stable_radixsort(arr, 8);
         }
@@ -378,7 +397,7 @@ impl<T: Radixable<u8>> Dispatcher<T, u8> for u8 {
         if arr.len() <= 500 {
             msd_stable_radixsort(arr, 8);
         } else {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
@@ -403,7 +422,7 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
         if arr.len() <= 500 {
             msd_stable_radixsort(arr, 8);
         } else {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
@@ -427,7 +446,7 @@ impl<T: Radixable<u16>> Dispatcher<T, u16> for u16 {
         if arr.len() <= 200 {
             arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
         } else {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
@@ -452,7 +471,7 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
         if arr.len() <= 200 {
             arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
         } else {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
@@ -466,7 +485,9 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
 
 impl<T: Radixable<u32>> Dispatcher<T, u32> for u32 {
     fn voracious_sort(&self, arr: &mut [T]) { lsd_radixsort(arr, 8); }
-    fn voracious_stable_sort(&self, arr: &mut [T]) { lsd_radixsort(arr, 8); }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        lsd_stable_radixsort(arr, 8);
+    }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
             arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
@@ -479,7 +500,9 @@ impl<T: Radixable<u32>> Dispatcher<T, u32> for u32 {
 #[cfg(target_pointer_width = "32")]
 impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
     fn voracious_sort(&self, arr: &mut [T]) { lsd_radixsort(arr, 8); }
-    fn voracious_stable_sort(&self, arr: &mut [T]) { lsd_radixsort(arr, 8); }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        lsd_stable_radixsort(arr, 8);
+    }
     fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
         if arr.len() <= 256 {
             arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
@@ -503,7 +526,7 @@ impl<T: Radixable<u64>> Dispatcher<T, u64> for u64 {
         } else if arr.len() <= 8000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         } else {
             msd_stable_radixsort(arr, 8);
         }
@@ -532,7 +555,7 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
         } else if arr.len() <= 8000 {
             msd_stable_radixsort(arr, 8);
         } else if arr.len() <= 100_000 {
-            lsd_radixsort(arr, 8);
+            lsd_stable_radixsort(arr, 8);
         } else {
             msd_stable_radixsort(arr, 8);
         }
@@ -574,3 +597,608 @@ impl<T: Radixable<usize>> Dispatcher<T, usize> for usize {
         }
     }
 }
+
+impl<T, U> Dispatcher<T, Option<U>> for Option<U>
+where
+    T: Radixable<Option<U>>,
+    U: RadixKey,
+    U::Key: WidenKey,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            voracious_sort(arr, 8);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            msd_stable_radixsort(arr, 8);
+        }
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 650_000, thread_n);
+        }
+    }
+}
+
+impl<T, U> Dispatcher<T, NoneLast<U>> for NoneLast<U>
+where
+    T: Radixable<NoneLast<U>>,
+    U: RadixKey,
+    U::Key: WidenKey,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            voracious_sort(arr, 8);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            msd_stable_radixsort(arr, 8);
+        }
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 650_000, thread_n);
+        }
+    }
+}
+
+impl<T: Radixable<Duration>> Dispatcher<T, Duration> for Duration {
+    fn voracious_sort(&self, arr: &mut [T]) { voracious_sort(arr, 8); }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        msd_stable_radixsort(arr, 8);
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 650_000, thread_n);
+        }
+    }
+}
+
+impl<T: Radixable<SystemTime>> Dispatcher<T, SystemTime> for SystemTime {
+    fn voracious_sort(&self, arr: &mut [T]) { voracious_sort(arr, 8); }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        msd_stable_radixsort(arr, 8);
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 650_000, thread_n);
+        }
+    }
+}
+
+impl<T: Radixable<Ipv6Addr>> Dispatcher<T, Ipv6Addr> for Ipv6Addr {
+    fn voracious_sort(&self, arr: &mut [T]) { voracious_sort(arr, 8); }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        msd_stable_radixsort(arr, 8);
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 650_000, thread_n);
+        }
+    }
+}
+
+impl<T: Radixable<Ipv4Addr>> Dispatcher<T, Ipv4Addr> for Ipv4Addr {
+    fn voracious_sort(&self, arr: &mut [T]) { lsd_radixsort(arr, 8); }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        lsd_stable_radixsort(arr, 8);
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 1_150_000, thread_n);
+        }
+    }
+}
+
+// An item with a `NonZero` key, seen as an item with the base integer of the
+// key, so that it is sorted by the dispatcher tuned for that integer.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+struct NonZeroItem<T, K>(T, PhantomData<K>);
+
+impl<T: PartialOrd, K> PartialOrd for NonZeroItem<T, K> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.0.partial_cmp(&other.0)
+    }
+}
+
+impl<T: PartialEq, K> PartialEq for NonZeroItem<T, K> {
+    fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+}
+
+fn as_base_items<T, K>(arr: &mut [T]) -> &mut [NonZeroItem<T, K>] {
+    // `NonZeroItem<T, K>` is a transparent wrapper of `T`.
+    unsafe { &mut *(arr as *mut [T] as *mut [NonZeroItem<T, K>]) }
+}
+
+impl<T> Radixable<u8> for NonZeroItem<T, NonZeroU8>
+where
+    T: Radixable<NonZeroU8, Key = NonZeroU8>,
+{
+    type Key = u8;
+
+    #[inline]
+    fn key(&self) -> u8 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroU8> for NonZeroU8
+where
+    T: Radixable<NonZeroU8, Key = NonZeroU8>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<u16> for NonZeroItem<T, NonZeroU16>
+where
+    T: Radixable<NonZeroU16, Key = NonZeroU16>,
+{
+    type Key = u16;
+
+    #[inline]
+    fn key(&self) -> u16 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroU16> for NonZeroU16
+where
+    T: Radixable<NonZeroU16, Key = NonZeroU16>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<u32> for NonZeroItem<T, NonZeroU32>
+where
+    T: Radixable<NonZeroU32, Key = NonZeroU32>,
+{
+    type Key = u32;
+
+    #[inline]
+    fn key(&self) -> u32 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroU32> for NonZeroU32
+where
+    T: Radixable<NonZeroU32, Key = NonZeroU32>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<u64> for NonZeroItem<T, NonZeroU64>
+where
+    T: Radixable<NonZeroU64, Key = NonZeroU64>,
+{
+    type Key = u64;
+
+    #[inline]
+    fn key(&self) -> u64 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroU64> for NonZeroU64
+where
+    T: Radixable<NonZeroU64, Key = NonZeroU64>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<u128> for NonZeroItem<T, NonZeroU128>
+where
+    T: Radixable<NonZeroU128, Key = NonZeroU128>,
+{
+    type Key = u128;
+
+    #[inline]
+    fn key(&self) -> u128 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroU128> for NonZeroU128
+where
+    T: Radixable<NonZeroU128, Key = NonZeroU128>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<usize> for NonZeroItem<T, NonZeroUsize>
+where
+    T: Radixable<NonZeroUsize, Key = NonZeroUsize>,
+{
+    type Key = usize;
+
+    #[inline]
+    fn key(&self) -> usize { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroUsize> for NonZeroUsize
+where
+    T: Radixable<NonZeroUsize, Key = NonZeroUsize>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<i8> for NonZeroItem<T, NonZeroI8>
+where
+    T: Radixable<NonZeroI8, Key = NonZeroI8>,
+{
+    type Key = i8;
+
+    #[inline]
+    fn key(&self) -> i8 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroI8> for NonZeroI8
+where
+    T: Radixable<NonZeroI8, Key = NonZeroI8>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<i16> for NonZeroItem<T, NonZeroI16>
+where
+    T: Radixable<NonZeroI16, Key = NonZeroI16>,
+{
+    type Key = i16;
+
+    #[inline]
+    fn key(&self) -> i16 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroI16> for NonZeroI16
+where
+    T: Radixable<NonZeroI16, Key = NonZeroI16>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<i32> for NonZeroItem<T, NonZeroI32>
+where
+    T: Radixable<NonZeroI32, Key = NonZeroI32>,
+{
+    type Key = i32;
+
+    #[inline]
+    fn key(&self) -> i32 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroI32> for NonZeroI32
+where
+    T: Radixable<NonZeroI32, Key = NonZeroI32>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<i64> for NonZeroItem<T, NonZeroI64>
+where
+    T: Radixable<NonZeroI64, Key = NonZeroI64>,
+{
+    type Key = i64;
+
+    #[inline]
+    fn key(&self) -> i64 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroI64> for NonZeroI64
+where
+    T: Radixable<NonZeroI64, Key = NonZeroI64>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<i128> for NonZeroItem<T, NonZeroI128>
+where
+    T: Radixable<NonZeroI128, Key = NonZeroI128>,
+{
+    type Key = i128;
+
+    #[inline]
+    fn key(&self) -> i128 { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroI128> for NonZeroI128
+where
+    T: Radixable<NonZeroI128, Key = NonZeroI128>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T> Radixable<isize> for NonZeroItem<T, NonZeroIsize>
+where
+    T: Radixable<NonZeroIsize, Key = NonZeroIsize>,
+{
+    type Key = isize;
+
+    #[inline]
+    fn key(&self) -> isize { self.0.key().get() }
+}
+
+impl<T> Dispatcher<T, NonZeroIsize> for NonZeroIsize
+where
+    T: Radixable<NonZeroIsize, Key = NonZeroIsize>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        Dispatcher::voracious_stable_sort(&self.get(), as_base_items(arr));
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        let arr = as_base_items(arr);
+        Dispatcher::voracious_mt_sort(&self.get(), arr, thread_n);
+    }
+}
+
+impl<T, U> Dispatcher<T, Wrapping<U>> for Wrapping<U>
+where
+    T: Radixable<Wrapping<U>>,
+    U: RadixKey + Copy + PartialOrd + Send + Sync,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            voracious_sort(arr, 8);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            msd_stable_radixsort(arr, 8);
+        }
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 650_000, thread_n);
+        }
+    }
+}
+
+impl<T, U> Dispatcher<T, Reverse<U>> for Reverse<U>
+where
+    T: Radixable<Reverse<U>>,
+    U: RadixKey + Copy + PartialOrd + Send + Sync,
+    U::Key: Not<Output = U::Key>,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            voracious_sort(arr, 8);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            msd_stable_radixsort(arr, 8);
+        }
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 650_000, thread_n);
+        }
+    }
+}
+
+impl<T: Radixable<F16>> Dispatcher<T, F16> for F16 {
+    fn voracious_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            lsd_radixsort(arr, 8);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            lsd_stable_radixsort(arr, 8);
+        }
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 1_150_000, thread_n);
+        }
+    }
+}
+
+impl<T: Radixable<BF16>> Dispatcher<T, BF16> for BF16 {
+    fn voracious_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            lsd_radixsort(arr, 8);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            lsd_stable_radixsort(arr, 8);
+        }
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 1_150_000, thread_n);
+        }
+    }
+}
+
+impl<T, A, B> Dispatcher<T, CompositeKey<A, B>> for CompositeKey<A, B>
+where
+    T: Radixable<CompositeKey<A, B>>,
+    A: RadixKey + Copy + PartialOrd + Send + Sync,
+    B: RadixKey + Copy + PartialOrd + Send + Sync,
+{
+    fn voracious_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            voracious_sort(arr, 8);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            msd_stable_radixsort(arr, 8);
+        }
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 650_000, thread_n);
+        }
+    }
+}
+
+impl<T: Radixable<KeySpec>> Dispatcher<T, KeySpec> for KeySpec {
+    fn voracious_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            voracious_sort(arr, 8);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [T]) {
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            msd_stable_radixsort(arr, 8);
+        }
+    }
+    fn voracious_mt_sort(&self, arr: &mut [T], thread_n: usize) {
+        if arr.len() <= 256 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            peeka_sort(arr, 8, 650_000, thread_n);
+        }
+    }
+}
@@ -1,4 +1,7 @@
+pub mod checked_sort;
+pub mod collect_sorted;
 pub mod dispatcher;
 pub mod radix_key;
 pub mod radixable;
 pub mod radixsort;
+pub mod sort_by_key;
@@ -1,4 +1,17 @@
-use std::ops::{BitAnd, BitOrAssign, Shl, Shr};
+use std::cmp::Reverse;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize,
+    NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+    Wrapping,
+};
+use std::ops::{BitAnd, BitOrAssign, Not, Shl, Shr};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use super::super::types::composite::CompositeKey;
+use super::super::types::half::{half_to_key, BF16, F16};
+use super::super::types::key_spec::KeySpec;
+use super::super::types::option::NoneLast;
 
 pub trait RadixKey {
     type Key: Copy
@@ -441,3 +454,606 @@ impl RadixKey for isize {
     #[inline]
     fn one(&self) -> Self::Key { 1 }
 }
+
+/// Key types that can be widened by at least one bit.
+///
+/// An `Option` key is encoded as the key of its inner value with one more
+/// leading bit for the `None`, so the inner key must fit in a wider integer.
+/// There is no wider integer than `u128`, thus `Option<u128>`,
+/// `Option<i128>` (and `Option<usize>` on 128 bits targets) are not supported.
+pub trait WidenKey: Copy {
+    type Wide: Copy
+        + Shl<Output = Self::Wide>
+        + Shr<Output = Self::Wide>
+        + BitAnd<Output = Self::Wide>
+        + BitOrAssign
+        + PartialEq
+        + PartialOrd
+        + Ord
+        + Send
+        + Sync
+        + std::fmt::Display;
+    const BITS: usize;
+    fn widen(self) -> Self::Wide;
+    fn wide_from_usize(item: usize) -> Self::Wide;
+    fn wide_to_usize(item: Self::Wide) -> usize;
+}
+
+impl WidenKey for u8 {
+    type Wide = u16;
+    const BITS: usize = 8;
+    #[inline]
+    fn widen(self) -> Self::Wide { self as u16 }
+    #[inline]
+    fn wide_from_usize(item: usize) -> Self::Wide { item as u16 }
+    #[inline]
+    fn wide_to_usize(item: Self::Wide) -> usize { item as usize }
+}
+
+impl WidenKey for u16 {
+    type Wide = u32;
+    const BITS: usize = 16;
+    #[inline]
+    fn widen(self) -> Self::Wide { self as u32 }
+    #[inline]
+    fn wide_from_usize(item: usize) -> Self::Wide { item as u32 }
+    #[inline]
+    fn wide_to_usize(item: Self::Wide) -> usize { item as usize }
+}
+
+impl WidenKey for u32 {
+    type Wide = u64;
+    const BITS: usize = 32;
+    #[inline]
+    fn widen(self) -> Self::Wide { self as u64 }
+    #[inline]
+    fn wide_from_usize(item: usize) -> Self::Wide { item as u64 }
+    #[inline]
+    fn wide_to_usize(item: Self::Wide) -> usize { item as usize }
+}
+
+impl WidenKey for u64 {
+    type Wide = u128;
+    const BITS: usize = 64;
+    #[inline]
+    fn widen(self) -> Self::Wide { self as u128 }
+    #[inline]
+    fn wide_from_usize(item: usize) -> Self::Wide { item as u128 }
+    #[inline]
+    fn wide_to_usize(item: Self::Wide) -> usize { item as usize }
+}
+
+// None first: the leading bit is set for Some values.
+impl<T> RadixKey for Option<T>
+where
+    T: RadixKey,
+    T::Key: WidenKey,
+{
+    type Key = <T::Key as WidenKey>::Wide;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        match self {
+            None => <T::Key as WidenKey>::wide_from_usize(0),
+            Some(value) => {
+                let mut key = value.into_keytype().widen();
+                key |= <T::Key as WidenKey>::wide_from_usize(1)
+                    << <T::Key as WidenKey>::wide_from_usize(
+                        <T::Key as WidenKey>::BITS,
+                    );
+                key
+            },
+        }
+    }
+    #[inline]
+    fn type_size(&self) -> usize { <T::Key as WidenKey>::BITS + 1 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        <T::Key as WidenKey>::wide_from_usize(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        <T::Key as WidenKey>::wide_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key {
+        <T::Key as WidenKey>::wide_from_usize(0)
+    }
+    #[inline]
+    fn one(&self) -> Self::Key { <T::Key as WidenKey>::wide_from_usize(1) }
+}
+
+// None last: the leading bit is set for None values.
+impl<T> RadixKey for NoneLast<T>
+where
+    T: RadixKey,
+    T::Key: WidenKey,
+{
+    type Key = <T::Key as WidenKey>::Wide;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        match &self.0 {
+            None => {
+                <T::Key as WidenKey>::wide_from_usize(1)
+                    << <T::Key as WidenKey>::wide_from_usize(
+                        <T::Key as WidenKey>::BITS,
+                    )
+            },
+            Some(value) => value.into_keytype().widen(),
+        }
+    }
+    #[inline]
+    fn type_size(&self) -> usize { <T::Key as WidenKey>::BITS + 1 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        <T::Key as WidenKey>::wide_from_usize(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        <T::Key as WidenKey>::wide_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key {
+        <T::Key as WidenKey>::wide_from_usize(0)
+    }
+    #[inline]
+    fn one(&self) -> Self::Key { <T::Key as WidenKey>::wide_from_usize(1) }
+}
+
+impl RadixKey for Duration {
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        ((self.as_secs() as u128) << 32) | self.subsec_nanos() as u128
+    }
+    #[inline]
+    fn type_size(&self) -> usize { 96 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+// Times before the epoch are mirrored below the 97th bit.
+impl RadixKey for SystemTime {
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => (1 << 96) | after.into_keytype(),
+            Err(before) => (1 << 96) - before.duration().into_keytype(),
+        }
+    }
+    #[inline]
+    fn type_size(&self) -> usize { 97 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+impl RadixKey for Ipv4Addr {
+    type Key = u32;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { u32::from(*self) }
+    #[inline]
+    fn type_size(&self) -> usize { 32 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u32 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+impl RadixKey for Ipv6Addr {
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { u128::from(*self) }
+    #[inline]
+    fn type_size(&self) -> usize { 128 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+impl RadixKey for NonZeroU8 {
+    type Key = <u8 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroU16 {
+    type Key = <u16 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroU32 {
+    type Key = <u32 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroU64 {
+    type Key = <u64 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroU128 {
+    type Key = <u128 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroUsize {
+    type Key = <usize as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroI8 {
+    type Key = <i8 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroI16 {
+    type Key = <i16 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroI32 {
+    type Key = <i32 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroI64 {
+    type Key = <i64 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroI128 {
+    type Key = <i128 as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl RadixKey for NonZeroIsize {
+    type Key = <isize as RadixKey>::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.get().into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.get().type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.get().usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.get().keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.get().default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.get().one() }
+}
+
+impl<T: RadixKey> RadixKey for Wrapping<T> {
+    type Key = T::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.0.into_keytype() }
+    #[inline]
+    fn type_size(&self) -> usize { self.0.type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.0.usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.0.keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.0.default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.0.one() }
+}
+
+// Descending order: the meaningful bits of the inner key are inverted, the
+// unused leading bits are kept to zero.
+impl<T> RadixKey for Reverse<T>
+where
+    T: RadixKey,
+    T::Key: Not<Output = T::Key>,
+{
+    type Key = T::Key;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        let width = std::mem::size_of::<T::Key>() * 8;
+        let shift = self.0.usize_to_keytype(width - self.0.type_size());
+        (!self.0.into_keytype() << shift) >> shift
+    }
+    #[inline]
+    fn type_size(&self) -> usize { self.0.type_size() }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key {
+        self.0.usize_to_keytype(item)
+    }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize {
+        self.0.keytype_to_usize(item)
+    }
+    #[inline]
+    fn default_key(&self) -> Self::Key { self.0.default_key() }
+    #[inline]
+    fn one(&self) -> Self::Key { self.0.one() }
+}
+
+impl RadixKey for F16 {
+    type Key = u16;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { half_to_key(self.0) }
+    #[inline]
+    fn type_size(&self) -> usize { 16 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u16 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+impl RadixKey for BF16 {
+    type Key = u16;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { half_to_key(self.0) }
+    #[inline]
+    fn type_size(&self) -> usize { 16 }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u16 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+// The key is split into 32 bits chunks, so that only the RadixKey methods are
+// needed, whatever the key type is.
+#[inline]
+pub(crate) fn key_to_u128<T: RadixKey>(value: &T) -> u128 {
+    let key = value.into_keytype();
+    let mask = value.usize_to_keytype(0xFFFF_FFFF);
+    let mut wide = 0;
+    let mut shift = 0;
+    while shift < value.type_size() {
+        let chunk = (key >> value.usize_to_keytype(shift)) & mask;
+        wide |= (value.keytype_to_usize(chunk) as u128) << shift;
+        shift += 32;
+    }
+    wide
+}
+
+impl<A: RadixKey, B: RadixKey> RadixKey for CompositeKey<A, B> {
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key {
+        // Asserts that the two keys fit in 128 bits, the shift would drop
+        // the high bits otherwise.
+        self.type_size();
+        let low_size = self.1.type_size();
+        let high = key_to_u128(&self.0);
+        let low = key_to_u128(&self.1);
+        if low_size == 128 {
+            low
+        } else {
+            (high << low_size) | low
+        }
+    }
+    #[inline]
+    fn type_size(&self) -> usize {
+        let size = self.0.type_size() + self.1.type_size();
+        assert!(size <= 128, "A composite key can not exceed 128 bits.");
+        size
+    }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
+
+impl RadixKey for KeySpec {
+    type Key = u128;
+    #[inline]
+    fn into_keytype(&self) -> Self::Key { self.to_u128() }
+    // An empty key spec still has a one bit key.
+    #[inline]
+    fn type_size(&self) -> usize { self.bits().max(1) }
+    #[inline]
+    fn usize_to_keytype(&self, item: usize) -> Self::Key { item as u128 }
+    #[inline]
+    fn keytype_to_usize(&self, item: Self::Key) -> usize { item as usize }
+    #[inline]
+    fn default_key(&self) -> Self::Key { 0 }
+    #[inline]
+    fn one(&self) -> Self::Key { 1 }
+}
@@ -1,3 +1,7 @@
+use super::super::sorts::cached_key_sort::{
+    cached_key_mt_sort, cached_key_sort, cached_key_stable_sort,
+    use_cached_keys,
+};
 use super::super::sorts::utils::{
     compute_max_level, compute_offset, get_full_histograms, Params,
 };
@@ -121,6 +125,10 @@ pub trait Radixable<K: RadixKey>: Copy + PartialOrd + Send + Sync {
     fn is_i64(&self) -> bool { false }
     #[inline]
     fn is_i128(&self) -> bool { false }
+    // If `key()` is expensive, this method should return true: the keys are
+    // then extracted once and cached before the sort.
+    #[inline]
+    fn cache_key(&self) -> bool { false }
     #[inline]
     fn mask_for_high_bits(
         &self,
@@ -155,20 +163,32 @@ pub trait Radixable<K: RadixKey>: Copy + PartialOrd + Send + Sync {
     }
     fn voracious_sort(&self, arr: &mut [Self]) {
         if arr.len() > 1 {
-            let dummy_key = arr[0].key();
-            Dispatcher::voracious_sort(&dummy_key, arr);
+            if use_cached_keys(self, arr.len()) {
+                cached_key_sort(arr);
+            } else {
+                let dummy_key = arr[0].key();
+                Dispatcher::voracious_sort(&dummy_key, arr);
+            }
         }
     }
     fn voracious_stable_sort(&self, arr: &mut [Self]) {
         if arr.len() > 1 {
-            let dummy_key = arr[0].key();
-            Dispatcher::voracious_stable_sort(&dummy_key, arr);
+            if use_cached_keys(self, arr.len()) {
+                cached_key_stable_sort(arr);
+            } else {
+                let dummy_key = arr[0].key();
+                Dispatcher::voracious_stable_sort(&dummy_key, arr);
+            }
         }
     }
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
         if arr.len() > 1 {
-            let dummy_key = arr[0].key();
-            Dispatcher::voracious_mt_sort(&dummy_key, arr, thread_n);
+            if use_cached_keys(self, arr.len()) {
+                cached_key_mt_sort(arr, thread_n);
+            } else {
+                let dummy_key = arr[0].key();
+                Dispatcher::voracious_mt_sort(&dummy_key, arr, thread_n);
+            }
         }
     }
 }
@@ -1,9 +1,40 @@
+#[cfg(feature = "checked")]
+use super::super::algo::checked::check;
+use super::super::sorts::cached_key_sort::{cached_key_sort, Mode};
+use super::super::sorts::range_sort::{check_range, range_sort_dispatch};
+use super::radix_key::key_to_u128;
 use super::super::{RadixKey, Radixable};
 
+// With the `checked` feature, every sort verifies its output.
+#[cfg(feature = "checked")]
+#[inline]
+fn verify<T: Radixable<K>, K: RadixKey>(arr: &[T]) {
+    if let Err(error) = check(arr) {
+        panic!("{}", error.reason());
+    }
+}
+
+#[cfg(not(feature = "checked"))]
+#[inline]
+fn verify<T: Radixable<K>, K: RadixKey>(_arr: &[T]) {}
+
 pub trait RadixSort<T: Radixable<K>, K: RadixKey> {
     fn voracious_sort(&mut self);
+    /// Items with equal keys keep their relative order, whatever the type
+    /// and the size of the array.
     fn voracious_stable_sort(&mut self);
     fn voracious_mt_sort(&mut self, thread_n: usize);
+    /// The keys are extracted once, for types whose `key()` is expensive.
+    fn voracious_sort_by_cached_key(&mut self);
+    /// Every key lies in `min..=max`: the keys are not scanned, and only the
+    /// digits of `key - min` are sorted. The order is unspecified if a key is
+    /// out of the range, with the `checked` feature it panics. Panics if `min`
+    /// is above `max`.
+    fn voracious_sort_with_range(
+        &mut self,
+        min: <T as Radixable<K>>::Key,
+        max: <T as Radixable<K>>::Key,
+    );
 }
 
 impl<T: Radixable<K>, K: RadixKey> RadixSort<T, K> for [T] {
@@ -11,28 +42,55 @@ impl<T: Radixable<K>, K: RadixKey> RadixSort<T, K> for [T] {
         if !self.is_empty() {
             let dummy = self[0];
             dummy.voracious_sort(self);
+            verify(self);
         }
     }
     fn voracious_stable_sort(&mut self) {
         if !self.is_empty() {
             let dummy = self[0];
             dummy.voracious_stable_sort(self);
+            verify(self);
         }
     }
     fn voracious_mt_sort(&mut self, thread_n: usize) {
         if !self.is_empty() {
             let dummy = self[0];
             dummy.voracious_mt_sort(self, thread_n);
+            verify(self);
         }
     }
+    fn voracious_sort_by_cached_key(&mut self) {
+        cached_key_sort(self);
+        verify(self);
+    }
+    fn voracious_sort_with_range(
+        &mut self,
+        min: <T as Radixable<K>>::Key,
+        max: <T as Radixable<K>>::Key,
+    ) {
+        let (min, max) = (key_to_u128(&min), key_to_u128(&max));
+        check_range(self, min, max);
+        range_sort_dispatch(self, min, max, Mode::Unstable);
+        verify(self);
+    }
 }
 
 impl<T: Radixable<K>, K: RadixKey> RadixSort<T, K> for Vec<T> {
     fn voracious_sort(&mut self) { self.as_mut_slice().voracious_sort(); }
     fn voracious_stable_sort(&mut self) {
-        self.as_mut_slice().voracious_sort();
+        self.as_mut_slice().voracious_stable_sort();
     }
     fn voracious_mt_sort(&mut self, thread_n: usize) {
         self.as_mut_slice().voracious_mt_sort(thread_n);
     }
+    fn voracious_sort_by_cached_key(&mut self) {
+        self.as_mut_slice().voracious_sort_by_cached_key();
+    }
+    fn voracious_sort_with_range(
+        &mut self,
+        min: <T as Radixable<K>>::Key,
+        max: <T as Radixable<K>>::Key,
+    ) {
+        self.as_mut_slice().voracious_sort_with_range(min, max);
+    }
 }
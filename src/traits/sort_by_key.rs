@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+
+use super::super::sorts::cached_key_sort::{
+    sort_by_extracted_key, sort_by_extracted_key_then, Mode,
+};
+use super::super::RadixKey;
+
+/// # Sorts by key
+///
+/// Sort any type by a radix key, the items do not need to implement
+/// `Radixable`, nor `Copy`. Records holding a `String`, a `Vec` or a `Box`
+/// can be sorted by one of their fields:
+///
+/// ```
+/// use voracious_radix_sort::RadixSortByKey;
+///
+/// struct Record {
+///     id: u64,
+///     name: String,
+/// }
+///
+/// let mut records = vec![
+///     Record { id: 3, name: "c".to_string() },
+///     Record { id: 1, name: "a".to_string() },
+///     Record { id: 2, name: "b".to_string() },
+/// ];
+/// records.voracious_sort_by_key(|record| record.id);
+/// let names: Vec<&str> = records.iter().map(|r| r.name.as_str()).collect();
+/// assert_eq!(names, vec!["a", "b", "c"]);
+/// ```
+///
+/// The key is extracted once per item into (key, index) pairs, which are
+/// sorted by the Voracious sort. The items are then moved in place with
+/// swaps. If the key function panics, the array is left untouched.
+///
+/// When only a part of the order is radixable, such as a timestamp then a
+/// name, `voracious_sort_by_key_then` sorts the items by the radix key, with
+/// an MSD radix sort, then each bucket of its last digit, which holds equal
+/// keys, by a comparator:
+///
+/// ```
+/// use voracious_radix_sort::RadixSortByKey;
+///
+/// let mut events = vec![(2_u64, "b"), (1, "z"), (2, "a"), (1, "c")];
+/// events.voracious_sort_by_key_then(|e| e.0, |a, b| a.1.cmp(b.1));
+/// assert_eq!(events, vec![(1, "c"), (1, "z"), (2, "a"), (2, "b")]);
+/// ```
+pub trait RadixSortByKey<T> {
+    fn voracious_sort_by_key<R, F>(&mut self, key: F)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R;
+    /// Items with equal keys keep their relative order.
+    fn voracious_stable_sort_by_key<R, F>(&mut self, key: F)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R;
+    /// Only the (key, index) pairs are sorted with several threads.
+    fn voracious_mt_sort_by_key<R, F>(&mut self, thread_n: usize, key: F)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R;
+    /// Items with equal keys are sorted by `then`, which is only called
+    /// within the runs of equal keys. The sort is stable.
+    fn voracious_sort_by_key_then<R, F, C>(&mut self, key: F, then: C)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R,
+        C: Fn(&T, &T) -> Ordering;
+}
+
+impl<T> RadixSortByKey<T> for [T] {
+    fn voracious_sort_by_key<R, F>(&mut self, key: F)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R,
+    {
+        sort_by_extracted_key(self, key, Mode::Unstable);
+    }
+    fn voracious_stable_sort_by_key<R, F>(&mut self, key: F)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R,
+    {
+        sort_by_extracted_key(self, key, Mode::Stable);
+    }
+    fn voracious_mt_sort_by_key<R, F>(&mut self, thread_n: usize, key: F)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R,
+    {
+        sort_by_extracted_key(self, key, Mode::Multithread(thread_n));
+    }
+    fn voracious_sort_by_key_then<R, F, C>(&mut self, key: F, then: C)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R,
+        C: Fn(&T, &T) -> Ordering,
+    {
+        sort_by_extracted_key_then(self, key, then);
+    }
+}
+
+impl<T> RadixSortByKey<T> for Vec<T> {
+    fn voracious_sort_by_key<R, F>(&mut self, key: F)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R,
+    {
+        self.as_mut_slice().voracious_sort_by_key(key);
+    }
+    fn voracious_stable_sort_by_key<R, F>(&mut self, key: F)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R,
+    {
+        self.as_mut_slice().voracious_stable_sort_by_key(key);
+    }
+    fn voracious_mt_sort_by_key<R, F>(&mut self, thread_n: usize, key: F)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R,
+    {
+        self.as_mut_slice().voracious_mt_sort_by_key(thread_n, key);
+    }
+    fn voracious_sort_by_key_then<R, F, C>(&mut self, key: F, then: C)
+    where
+        R: RadixKey,
+        F: Fn(&T) -> R,
+        C: Fn(&T, &T) -> Ordering,
+    {
+        self.as_mut_slice().voracious_sort_by_key_then(key, then);
+    }
+}
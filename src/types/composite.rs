@@ -0,0 +1,26 @@
+use super::super::{RadixKey, Radixable};
+
+/// # Composite key
+///
+/// Lexicographic key made of two keys: the first key is compared first, the
+/// second key breaks the ties. Composite keys can be nested to use more than
+/// two keys.
+///
+/// The keys are concatenated into a `u128`, thus the sum of the keys
+/// `type_size` must not exceed 128 bits.
+///
+/// The `PartialOrd` implementation is the lexicographic order of the two
+/// keys, the radix sorts use the order of the concatenated key.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct CompositeKey<A, B>(pub A, pub B);
+
+impl<A, B> Radixable<CompositeKey<A, B>> for CompositeKey<A, B>
+where
+    A: RadixKey + Copy + PartialOrd + Send + Sync,
+    B: RadixKey + Copy + PartialOrd + Send + Sync,
+{
+    type Key = CompositeKey<A, B>;
+
+    #[inline]
+    fn key(&self) -> CompositeKey<A, B> { *self }
+}
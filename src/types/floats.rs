@@ -2,6 +2,8 @@ use rayon::slice::ParallelSliceMut;
 
 use super::super::sorts::dlsd_sort::dlsd_radixsort;
 use super::super::sorts::lsd_sort::lsd_radixsort;
+use super::super::sorts::lsd_stable_sort::lsd_stable_radixsort;
+use super::super::sorts::msd_stable_sort::msd_stable_radixsort;
 use super::super::sorts::peeka_sort::peeka_sort;
 use super::super::sorts::rollercoaster_sort::rollercoaster_sort;
 use super::super::sorts::utils::{get_empty_histograms, Params};
@@ -194,7 +196,11 @@ impl Radixable<f32> for f32 {
         }
     }
     fn voracious_stable_sort(&self, arr: &mut [f32]) {
-        self.voracious_sort(arr);
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else {
+            lsd_stable_radixsort(arr, 8);
+        }
     }
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
         if arr.len() < 1_000_000 {
@@ -697,7 +703,13 @@ impl Radixable<f64> for f64 {
         }
     }
     fn voracious_stable_sort(&self, arr: &mut [f64]) {
-        self.voracious_sort(arr);
+        if arr.len() <= 200 {
+            arr.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        } else if arr.len() < 100_000 {
+            msd_stable_radixsort(arr, 8);
+        } else {
+            lsd_stable_radixsort(arr, 8);
+        }
     }
     fn voracious_mt_sort(&self, arr: &mut [Self], thread_n: usize) {
         if arr.len() < 800_000 {
@@ -0,0 +1,135 @@
+use std::cmp::Ordering;
+
+use super::super::dedicated::cs_u16::cs_u16;
+use super::super::sorts::lsd_sort::lsd_radixsort;
+use super::super::sorts::peeka_sort::peeka_sort;
+use super::super::Radixable;
+
+/// # Half precision float
+///
+/// Bit pattern of an IEEE 754 half precision float (1 sign bit, 5 exponent
+/// bits, 10 mantissa bits), stored as a raw `u16`.
+///
+/// The order is the one of the `f32` key: negative NaNs first, then the
+/// negative numbers, `-0`, `+0`, the positive numbers and the positive NaNs
+/// last.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct F16(pub u16);
+
+/// # Brain float
+///
+/// Bit pattern of a bfloat16 (1 sign bit, 8 exponent bits, 7 mantissa bits),
+/// stored as a raw `u16`. It is the upper half of an `f32`.
+///
+/// The order is the same as the `F16` order.
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BF16(pub u16);
+
+// Same sign flip as the f32 key, on 16 bits.
+#[inline]
+pub(crate) fn half_to_key(bits: u16) -> u16 {
+    let submask = 0x8000;
+
+    if bits & submask == submask {
+        bits ^ 0xFFFF
+    } else {
+        bits ^ submask
+    }
+}
+
+#[inline]
+fn key_to_half(key: u16) -> u16 {
+    let submask = 0x8000;
+
+    if key & submask == submask {
+        key ^ submask
+    } else {
+        key ^ 0xFFFF
+    }
+}
+
+// The bit patterns are turned into keys in place, sorted with the dedicated
+// counting sort and turned back into bit patterns.
+fn cs_half(bits: &mut [u16]) {
+    bits.iter_mut().for_each(|b| *b = half_to_key(*b));
+    cs_u16(bits);
+    bits.iter_mut().for_each(|b| *b = key_to_half(*b));
+}
+
+impl PartialOrd for F16 {
+    fn partial_cmp(&self, other: &F16) -> Option<Ordering> {
+        half_to_key(self.0).partial_cmp(&half_to_key(other.0))
+    }
+}
+
+impl PartialOrd for BF16 {
+    fn partial_cmp(&self, other: &BF16) -> Option<Ordering> {
+        half_to_key(self.0).partial_cmp(&half_to_key(other.0))
+    }
+}
+
+impl Radixable<F16> for F16 {
+    type Key = F16;
+
+    #[inline]
+    fn key(&self) -> F16 { *self }
+    #[inline]
+    fn extract(&self, mask: u16, shift: usize) -> usize {
+        ((self.into_key_type() & mask) >> shift) as usize
+    }
+    #[inline]
+    fn to_generic(&self, v: usize) -> F16 { F16(key_to_half(v as u16)) }
+    #[inline]
+    fn into_key_type(&self) -> u16 { half_to_key(self.0) }
+    fn voracious_sort(&self, arr: &mut [F16]) {
+        if arr.len() <= 230 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else if arr.len() <= 100_000 {
+            lsd_radixsort(arr, 8);
+        } else {
+            // F16 is a transparent wrapper around an u16.
+            let bits = unsafe { &mut *(arr as *mut [F16] as *mut [u16]) };
+            cs_half(bits);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [F16]) {
+        self.voracious_sort(arr);
+    }
+    fn voracious_mt_sort(&self, arr: &mut [F16], thread_n: usize) {
+        peeka_sort(arr, 8, 1_150_000, thread_n);
+    }
+}
+
+impl Radixable<BF16> for BF16 {
+    type Key = BF16;
+
+    #[inline]
+    fn key(&self) -> BF16 { *self }
+    #[inline]
+    fn extract(&self, mask: u16, shift: usize) -> usize {
+        ((self.into_key_type() & mask) >> shift) as usize
+    }
+    #[inline]
+    fn to_generic(&self, v: usize) -> BF16 { BF16(key_to_half(v as u16)) }
+    #[inline]
+    fn into_key_type(&self) -> u16 { half_to_key(self.0) }
+    fn voracious_sort(&self, arr: &mut [BF16]) {
+        if arr.len() <= 230 {
+            arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap());
+        } else if arr.len() <= 100_000 {
+            lsd_radixsort(arr, 8);
+        } else {
+            // BF16 is a transparent wrapper around an u16.
+            let bits = unsafe { &mut *(arr as *mut [BF16] as *mut [u16]) };
+            cs_half(bits);
+        }
+    }
+    fn voracious_stable_sort(&self, arr: &mut [BF16]) {
+        self.voracious_sort(arr);
+    }
+    fn voracious_mt_sort(&self, arr: &mut [BF16], thread_n: usize) {
+        peeka_sort(arr, 8, 1_150_000, thread_n);
+    }
+}
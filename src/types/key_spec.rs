@@ -0,0 +1,158 @@
+use super::super::traits::radix_key::{key_to_u128, WidenKey};
+use super::super::{NoneLast, RadixKey, Radixable};
+
+/// # Nulls
+///
+/// Placement of the `None` values of an `Option` field of a `KeySpec`,
+/// whatever the direction of the field is.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Nulls {
+    First,
+    Last,
+}
+
+/// # Key spec
+///
+/// Lexicographic key built from several fields, each with its own direction:
+/// the first field is compared first, the next fields break the ties. The
+/// fields of a descending direction have their key bits inverted.
+///
+/// The keys of the fields are concatenated into a `u128`, thus the sum of
+/// the fields `type_size` must not exceed 128 bits. Every item must give the
+/// same fields, in the same order.
+///
+/// A `KeySpec` is meant to be returned by `Radixable::key`; the
+/// `PartialOrd` implementation of the item must agree with it.
+///
+/// ```
+/// use std::cmp::Ordering;
+/// use voracious_radix_sort::{KeySpec, Nulls, RadixSort, Radixable};
+///
+/// #[derive(Copy, Clone, Debug, PartialEq)]
+/// struct Player {
+///     region: u8,
+///     score: f32,
+///     rank: Option<u16>,
+/// }
+///
+/// impl Player {
+///     fn spec(&self) -> KeySpec {
+///         KeySpec::new()
+///             .asc(self.region)
+///             .desc(self.score)
+///             .asc_option(self.rank, Nulls::Last)
+///     }
+/// }
+///
+/// impl PartialOrd for Player {
+///     fn partial_cmp(&self, other: &Player) -> Option<Ordering> {
+///         self.spec().partial_cmp(&other.spec())
+///     }
+/// }
+///
+/// impl Radixable<KeySpec> for Player {
+///     type Key = KeySpec;
+///     #[inline]
+///     fn key(&self) -> KeySpec { self.spec() }
+/// }
+///
+/// let mut players = vec![
+///     Player { region: 2, score: 0.5, rank: Some(1) },
+///     Player { region: 1, score: 0.5, rank: None },
+///     Player { region: 1, score: 0.5, rank: Some(4) },
+///     Player { region: 1, score: 0.9, rank: Some(7) },
+/// ];
+/// players.voracious_sort();
+///
+/// let order: Vec<_> = players.iter().map(|p| p.rank).collect();
+/// assert_eq!(order, vec![Some(7), Some(4), None, Some(1)]);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct KeySpec {
+    key: u128,
+    bits: usize,
+}
+
+#[inline]
+fn low_mask(bits: usize) -> u128 {
+    if bits == 128 {
+        u128::MAX
+    } else {
+        (1 << bits) - 1
+    }
+}
+
+impl KeySpec {
+    /// An empty key spec, without field.
+    pub fn new() -> KeySpec { KeySpec { key: 0, bits: 0 } }
+
+    #[inline]
+    fn push<R: RadixKey>(self, field: &R, descending: bool) -> KeySpec {
+        let field_bits = field.type_size();
+        let bits = self.bits + field_bits;
+        assert!(bits <= 128, "[KeySpec] The fields can not exceed 128 bits.");
+
+        let mut key = key_to_u128(field);
+        if descending {
+            key ^= low_mask(field_bits);
+        }
+        if field_bits < 128 {
+            key |= self.key << field_bits;
+        }
+
+        KeySpec { key, bits }
+    }
+
+    /// Add an ascending field.
+    #[inline]
+    pub fn asc<R: RadixKey>(self, field: R) -> KeySpec {
+        self.push(&field, false)
+    }
+
+    /// Add a descending field.
+    #[inline]
+    pub fn desc<R: RadixKey>(self, field: R) -> KeySpec {
+        self.push(&field, true)
+    }
+
+    /// Add an ascending `Option` field, with the `None` values placed by
+    /// `nulls`.
+    #[inline]
+    pub fn asc_option<R>(self, field: Option<R>, nulls: Nulls) -> KeySpec
+    where
+        R: RadixKey,
+        R::Key: WidenKey,
+    {
+        match nulls {
+            Nulls::First => self.push(&field, false),
+            Nulls::Last => self.push(&NoneLast(field), false),
+        }
+    }
+
+    /// Add a descending `Option` field, with the `None` values placed by
+    /// `nulls`.
+    #[inline]
+    pub fn desc_option<R>(self, field: Option<R>, nulls: Nulls) -> KeySpec
+    where
+        R: RadixKey,
+        R::Key: WidenKey,
+    {
+        match nulls {
+            Nulls::First => self.push(&NoneLast(field), true),
+            Nulls::Last => self.push(&field, true),
+        }
+    }
+
+    /// The concatenated key, on the `bits()` least significant bits.
+    pub fn to_u128(&self) -> u128 { self.key }
+
+    /// Number of bits of the concatenated key.
+    pub fn bits(&self) -> usize { self.bits }
+}
+
+impl Radixable<KeySpec> for KeySpec {
+    type Key = KeySpec;
+
+    #[inline]
+    fn key(&self) -> KeySpec { *self }
+}
@@ -1,8 +1,16 @@
 mod bool;
 mod char;
+pub mod composite;
 pub mod custom;
 mod floats;
+pub mod half;
+pub mod key_spec;
 mod isize;
+mod net;
+mod nonzero;
+pub mod option;
 mod signed_integer;
+mod time;
 mod unsigned_integer;
 mod usize;
+mod wrapper;
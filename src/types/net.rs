@@ -0,0 +1,17 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use super::super::Radixable;
+
+impl Radixable<Ipv4Addr> for Ipv4Addr {
+    type Key = Ipv4Addr;
+
+    #[inline]
+    fn key(&self) -> Ipv4Addr { *self }
+}
+
+impl Radixable<Ipv6Addr> for Ipv6Addr {
+    type Key = Ipv6Addr;
+
+    #[inline]
+    fn key(&self) -> Ipv6Addr { *self }
+}
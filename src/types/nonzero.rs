@@ -0,0 +1,90 @@
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize,
+    NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use super::super::Radixable;
+
+impl Radixable<NonZeroU8> for NonZeroU8 {
+    type Key = NonZeroU8;
+
+    #[inline]
+    fn key(&self) -> NonZeroU8 { *self }
+}
+
+impl Radixable<NonZeroU16> for NonZeroU16 {
+    type Key = NonZeroU16;
+
+    #[inline]
+    fn key(&self) -> NonZeroU16 { *self }
+}
+
+impl Radixable<NonZeroU32> for NonZeroU32 {
+    type Key = NonZeroU32;
+
+    #[inline]
+    fn key(&self) -> NonZeroU32 { *self }
+}
+
+impl Radixable<NonZeroU64> for NonZeroU64 {
+    type Key = NonZeroU64;
+
+    #[inline]
+    fn key(&self) -> NonZeroU64 { *self }
+}
+
+impl Radixable<NonZeroU128> for NonZeroU128 {
+    type Key = NonZeroU128;
+
+    #[inline]
+    fn key(&self) -> NonZeroU128 { *self }
+}
+
+impl Radixable<NonZeroUsize> for NonZeroUsize {
+    type Key = NonZeroUsize;
+
+    #[inline]
+    fn key(&self) -> NonZeroUsize { *self }
+}
+
+impl Radixable<NonZeroI8> for NonZeroI8 {
+    type Key = NonZeroI8;
+
+    #[inline]
+    fn key(&self) -> NonZeroI8 { *self }
+}
+
+impl Radixable<NonZeroI16> for NonZeroI16 {
+    type Key = NonZeroI16;
+
+    #[inline]
+    fn key(&self) -> NonZeroI16 { *self }
+}
+
+impl Radixable<NonZeroI32> for NonZeroI32 {
+    type Key = NonZeroI32;
+
+    #[inline]
+    fn key(&self) -> NonZeroI32 { *self }
+}
+
+impl Radixable<NonZeroI64> for NonZeroI64 {
+    type Key = NonZeroI64;
+
+    #[inline]
+    fn key(&self) -> NonZeroI64 { *self }
+}
+
+impl Radixable<NonZeroI128> for NonZeroI128 {
+    type Key = NonZeroI128;
+
+    #[inline]
+    fn key(&self) -> NonZeroI128 { *self }
+}
+
+impl Radixable<NonZeroIsize> for NonZeroIsize {
+    type Key = NonZeroIsize;
+
+    #[inline]
+    fn key(&self) -> NonZeroIsize { *self }
+}
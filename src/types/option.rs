@@ -0,0 +1,47 @@
+use std::cmp::Ordering;
+
+use super::super::traits::radix_key::WidenKey;
+use super::super::{RadixKey, Radixable};
+
+/// # None last
+///
+/// Wrapper around an `Option` which puts the `None` values after the `Some`
+/// values. A bare `Option` puts the `None` values first, like the Rust
+/// standard `Ord` implementation.
+///
+/// It can be sorted directly, or be used as the key of a custom struct.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NoneLast<T>(pub Option<T>);
+
+impl<T: PartialOrd> PartialOrd for NoneLast<T> {
+    fn partial_cmp(&self, other: &NoneLast<T>) -> Option<Ordering> {
+        match (&self.0, &other.0) {
+            (None, None) => Some(Ordering::Equal),
+            (None, Some(_)) => Some(Ordering::Greater),
+            (Some(_), None) => Some(Ordering::Less),
+            (Some(a), Some(b)) => a.partial_cmp(b),
+        }
+    }
+}
+
+impl<T> Radixable<Option<T>> for Option<T>
+where
+    T: RadixKey + Copy + PartialOrd + Send + Sync,
+    T::Key: WidenKey,
+{
+    type Key = Option<T>;
+
+    #[inline]
+    fn key(&self) -> Option<T> { *self }
+}
+
+impl<T> Radixable<NoneLast<T>> for NoneLast<T>
+where
+    T: RadixKey + Copy + PartialOrd + Send + Sync,
+    T::Key: WidenKey,
+{
+    type Key = NoneLast<T>;
+
+    #[inline]
+    fn key(&self) -> NoneLast<T> { *self }
+}
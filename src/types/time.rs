@@ -0,0 +1,17 @@
+use std::time::{Duration, SystemTime};
+
+use super::super::Radixable;
+
+impl Radixable<Duration> for Duration {
+    type Key = Duration;
+
+    #[inline]
+    fn key(&self) -> Duration { *self }
+}
+
+impl Radixable<SystemTime> for SystemTime {
+    type Key = SystemTime;
+
+    #[inline]
+    fn key(&self) -> SystemTime { *self }
+}
@@ -0,0 +1,26 @@
+use std::cmp::Reverse;
+use std::num::Wrapping;
+use std::ops::Not;
+
+use super::super::{RadixKey, Radixable};
+
+impl<T> Radixable<Wrapping<T>> for Wrapping<T>
+where
+    T: RadixKey + Copy + PartialOrd + Send + Sync,
+{
+    type Key = Wrapping<T>;
+
+    #[inline]
+    fn key(&self) -> Wrapping<T> { *self }
+}
+
+impl<T> Radixable<Reverse<T>> for Reverse<T>
+where
+    T: RadixKey + Copy + PartialOrd + Send + Sync,
+    T::Key: Not<Output = T::Key>,
+{
+    type Key = Reverse<T>;
+
+    #[inline]
+    fn key(&self) -> Reverse<T> { *self }
+}
@@ -0,0 +1,307 @@
+//! # Voracious sort derive
+//!
+//! `#[derive(Radixable)]` for the
+//! [voracious_radix_sort](https://docs.rs/voracious_radix_sort) crate.
+//!
+//! It is re-exported by `voracious_radix_sort` with the `derive` feature.
+//!
+//! ## Struct
+//!
+//! The key fields are marked with `#[radix(key)]`. When several fields are
+//! marked, the key is the lexicographic composite of the fields, in the
+//! declaration order (See `CompositeKey`, the fields must fit in 128 bits,
+//! it is a compile error when the primitive fields do not).
+//! A field marked with `#[radix(desc)]` is a key field sorted in descending
+//! order (See `std::cmp::Reverse`).
+//!
+//! ## Enum
+//!
+//! Fieldless enums are sorted by discriminant. The key type is the `repr`
+//! type of the enum, `isize` by default.
+//!
+//! ## Order
+//!
+//! `PartialOrd` and `PartialEq` are generated too, they compare the radix
+//! keys, so that they always agree with the `Radixable` implementation. Do
+//! not derive them.
+//!
+//! ```ignore
+//! use voracious_radix_sort::{RadixSort, Radixable};
+//!
+//! #[derive(Copy, Clone, Debug, Radixable)]
+//! struct Event {
+//!     #[radix(key)]
+//!     day: u16,
+//!     #[radix(desc)]
+//!     priority: u8,
+//!     id: u64,
+//! }
+//!
+//! #[derive(Copy, Clone, Debug, Radixable)]
+//! #[repr(u8)]
+//! enum Level {
+//!     Low = 1,
+//!     High = 3,
+//! }
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, Attribute, Data, DataEnum, DeriveInput, Error, Fields,
+    Ident, Index, Meta, NestedMeta,
+};
+
+const INT_REPRS: [&str; 12] = [
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+    "i128", "isize",
+];
+
+struct KeyField {
+    access: TokenStream2,
+    ty: syn::Type,
+    desc: bool,
+}
+
+#[proc_macro_derive(Radixable, attributes(radix))]
+pub fn derive_radixable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let expanded = match &input.data {
+        Data::Struct(data) => derive_struct(&input, &data.fields),
+        Data::Enum(data) => derive_enum(&input, data),
+        Data::Union(_) => Err(Error::new(
+            Span::call_site(),
+            "Radixable can not be derived for a union",
+        )),
+    };
+
+    match expanded {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+// Returns (key, desc) for the `#[radix(...)]` attributes of a field.
+fn parse_radix_attributes(attrs: &[Attribute]) -> Result<(bool, bool), Error> {
+    let mut key = false;
+    let mut desc = false;
+
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("radix")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => {
+                for nested in list.nested.iter() {
+                    match nested {
+                        NestedMeta::Meta(Meta::Path(path))
+                            if path.is_ident("key") =>
+                        {
+                            key = true;
+                        },
+                        NestedMeta::Meta(Meta::Path(path))
+                            if path.is_ident("desc") =>
+                        {
+                            key = true;
+                            desc = true;
+                        },
+                        _ => {
+                            return Err(Error::new_spanned(
+                                nested,
+                                "expected `key` or `desc`",
+                            ));
+                        },
+                    }
+                }
+            },
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "expected `#[radix(key)]` or `#[radix(desc)]`",
+                ));
+            },
+        }
+    }
+
+    Ok((key, desc))
+}
+
+fn derive_struct(
+    input: &DeriveInput,
+    fields: &Fields,
+) -> Result<TokenStream2, Error> {
+    let mut key_fields = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let (key, desc) = parse_radix_attributes(&field.attrs)?;
+        if key {
+            let access = match &field.ident {
+                Some(ident) => quote! { #ident },
+                None => {
+                    let index = Index::from(i);
+                    quote! { #index }
+                },
+            };
+            key_fields.push(KeyField { access, ty: field.ty.clone(), desc });
+        }
+    }
+
+    if key_fields.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "at least one field must be marked with `#[radix(key)]`",
+        ));
+    }
+
+    // The composite key is a u128: reject the fields whose widths are known
+    // to overflow it, the other fields are checked when the key is built.
+    let known_bits: usize =
+        key_fields.iter().filter_map(|field| key_bits(&field.ty)).sum();
+    if known_bits > 128 {
+        return Err(Error::new_spanned(
+            &input.ident,
+            format!(
+                "the key fields use {} bits, a composite key can not exceed \
+                 128 bits",
+                known_bits
+            ),
+        ));
+    }
+
+    // The composite is nested from the right: (a, (b, c)).
+    let mut pieces = key_fields.iter().rev().map(|field| {
+        let access = &field.access;
+        let ty = &field.ty;
+        if field.desc {
+            (
+                quote! { ::std::cmp::Reverse<#ty> },
+                quote! { ::std::cmp::Reverse(self.#access) },
+            )
+        } else {
+            (quote! { #ty }, quote! { self.#access })
+        }
+    });
+    let (mut key_ty, mut key_expr) = pieces.next().unwrap();
+    for (ty, expr) in pieces {
+        key_ty = quote! { ::voracious_radix_sort::CompositeKey<#ty, #key_ty> };
+        key_expr =
+            quote! { ::voracious_radix_sort::CompositeKey(#expr, #key_expr) };
+    }
+
+    Ok(impl_radixable(input, key_ty, key_expr))
+}
+
+// Width of the radix key of a primitive type, `None` for the other types and
+// for `usize` and `isize`, whose width depends on the target.
+fn key_bits(ty: &syn::Type) -> Option<usize> {
+    let ident = match ty {
+        syn::Type::Path(path) if path.qself.is_none() => {
+            path.path.get_ident()?
+        },
+        _ => return None,
+    };
+    let bits = match ident.to_string().as_str() {
+        "bool" | "u8" | "i8" => 8,
+        "u16" | "i16" => 16,
+        "char" | "f32" | "u32" | "i32" => 32,
+        "f64" | "u64" | "i64" => 64,
+        "u128" | "i128" => 128,
+        _ => return None,
+    };
+
+    Some(bits)
+}
+
+fn enum_repr(attrs: &[Attribute]) -> Ident {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let Ok(Meta::List(list)) = attr.parse_meta() {
+            for nested in list.nested.iter() {
+                if let NestedMeta::Meta(Meta::Path(path)) = nested {
+                    if let Some(ident) = path.get_ident() {
+                        if INT_REPRS.iter().any(|repr| ident == repr) {
+                            return ident.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ident::new("isize", Span::call_site())
+}
+
+fn derive_enum(
+    input: &DeriveInput,
+    data: &DataEnum,
+) -> Result<TokenStream2, Error> {
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|variant| !matches!(variant.fields, Fields::Unit))
+    {
+        return Err(Error::new(
+            variant.fields.span(),
+            "Radixable can only be derived for fieldless enums",
+        ));
+    }
+    if data.variants.is_empty() {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "Radixable can not be derived for an empty enum",
+        ));
+    }
+
+    let repr = enum_repr(&input.attrs);
+
+    Ok(impl_radixable(input, quote! { #repr }, quote! { *self as #repr }))
+}
+
+fn impl_radixable(
+    input: &DeriveInput,
+    key_ty: TokenStream2,
+    key_expr: TokenStream2,
+) -> TokenStream2 {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) =
+        input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::voracious_radix_sort::Radixable<#key_ty>
+            for #name #ty_generics #where_clause
+        {
+            type Key = #key_ty;
+            #[inline]
+            fn key(&self) -> Self::Key { #key_expr }
+        }
+
+        impl #impl_generics ::std::cmp::PartialOrd
+            for #name #ty_generics #where_clause
+        {
+            #[inline]
+            fn partial_cmp(
+                &self,
+                other: &Self,
+            ) -> ::std::option::Option<::std::cmp::Ordering> {
+                let a = <Self as ::voracious_radix_sort::Radixable<#key_ty>>
+                    ::into_key_type(self);
+                let b = <Self as ::voracious_radix_sort::Radixable<#key_ty>>
+                    ::into_key_type(other);
+                ::std::option::Option::Some(a.cmp(&b))
+            }
+        }
+
+        impl #impl_generics ::std::cmp::PartialEq
+            for #name #ty_generics #where_clause
+        {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                let a = <Self as ::voracious_radix_sort::Radixable<#key_ty>>
+                    ::into_key_type(self);
+                let b = <Self as ::voracious_radix_sort::Radixable<#key_ty>>
+                    ::into_key_type(other);
+                a == b
+            }
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use std::cmp::Ordering;
+
+use rand::{thread_rng, Rng};
+use voracious_radix_sort::{RadixSort, Radixable};
+
+#[derive(Copy, Clone, Debug, Radixable)]
+struct Single {
+    #[radix(key)]
+    value: i32,
+    other: usize,
+}
+
+#[derive(Copy, Clone, Debug, Radixable)]
+struct Event {
+    #[radix(key)]
+    day: u16,
+    #[radix(desc)]
+    priority: u8,
+    #[radix(key)]
+    score: f32,
+    id: usize,
+}
+
+#[derive(Copy, Clone, Debug, Radixable)]
+struct Pair(#[radix(key)] u8, u64, #[radix(key, desc)] i16);
+
+#[derive(Copy, Clone, Debug, Radixable)]
+#[repr(u8)]
+enum Level {
+    Low = 2,
+    Medium = 1,
+    High = 0,
+}
+
+#[derive(Copy, Clone, Debug, Radixable)]
+enum Signed {
+    Negative = -5,
+    Zero = 0,
+    Positive = 5,
+}
+
+fn random_events(size: usize) -> Vec<Event> {
+    let mut rng = thread_rng();
+    (0..size)
+        .map(|id| Event {
+            day: rng.gen_range(0, 30),
+            priority: rng.gen_range(0, 4),
+            score: rng.gen_range(-10.0, 10.0),
+            id,
+        })
+        .collect()
+}
+
+fn compare_events(a: &Event, b: &Event) -> Ordering {
+    a.day
+        .cmp(&b.day)
+        .then(b.priority.cmp(&a.priority))
+        .then(a.score.partial_cmp(&b.score).unwrap())
+}
+
+#[test]
+fn test_derive_single_key() {
+    let mut rng = thread_rng();
+    let mut array: Vec<Single> = (0..50_000)
+        .map(|other| Single { value: rng.gen(), other })
+        .collect();
+    let mut check = array.to_vec();
+    array.voracious_stable_sort();
+    check.sort_by_key(|s| s.value);
+    assert!(array
+        .iter()
+        .zip(check.iter())
+        .all(|(a, b)| a.value == b.value && a.other == b.other));
+    assert_eq!(Single { value: 3, other: 0 }, Single { value: 3, other: 1 });
+}
+
+#[test]
+fn test_derive_composite_key() {
+    for size in [0, 1, 200, 5_000, 50_000].iter() {
+        let mut array = random_events(*size);
+        let mut check = array.to_vec();
+        array.voracious_stable_sort();
+        check.sort_by(compare_events);
+        assert!(array
+            .iter()
+            .zip(check.iter())
+            .all(|(a, b)| compare_events(a, b) == Ordering::Equal));
+        assert_eq!(array.iter().map(|e| e.id).sum::<usize>(), (0..*size).sum());
+
+        let mut array = random_events(*size);
+        array.voracious_sort();
+        assert!(array
+            .windows(2)
+            .all(|w| compare_events(&w[0], &w[1]) != Ordering::Greater));
+
+        let mut array = random_events(*size);
+        array.voracious_mt_sort(4);
+        assert!(array
+            .windows(2)
+            .all(|w| compare_events(&w[0], &w[1]) != Ordering::Greater));
+    }
+}
+
+#[test]
+fn test_derive_order_agrees_with_key() {
+    let events = random_events(1_000);
+    for a in events.iter().take(100) {
+        for b in events.iter() {
+            assert_eq!(a.partial_cmp(b), Some(compare_events(a, b)));
+        }
+    }
+}
+
+#[test]
+fn test_derive_tuple_struct() {
+    let mut rng = thread_rng();
+    let mut array: Vec<Pair> = (0..10_000)
+        .map(|i| Pair(rng.gen_range(0, 8), i, rng.gen()))
+        .collect();
+    let mut check = array.to_vec();
+    array.voracious_sort();
+    check.sort_by(|a, b| a.0.cmp(&b.0).then(b.2.cmp(&a.2)));
+    assert!(array
+        .iter()
+        .zip(check.iter())
+        .all(|(a, b)| a.0 == b.0 && a.2 == b.2));
+    let mut ids: Vec<u64> = array.iter().map(|p| p.1).collect();
+    ids.sort_unstable();
+    assert_eq!(ids, (0..10_000).collect::<Vec<u64>>());
+}
+
+#[test]
+fn test_derive_enum() {
+    let mut array = vec![Level::Low, Level::High, Level::Medium, Level::Low];
+    array.voracious_sort();
+    assert_eq!(array, vec![Level::High, Level::Medium, Level::Low, Level::Low]);
+    assert_eq!(Level::Low.key(), 2u8);
+
+    let mut array = vec![Signed::Positive, Signed::Zero, Signed::Negative];
+    array.voracious_sort();
+    assert_eq!(array, vec![Signed::Negative, Signed::Zero, Signed::Positive]);
+    assert_eq!(Signed::Negative.key(), -5isize);
+}
+